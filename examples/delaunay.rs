@@ -9,8 +9,6 @@ fn main() -> Result<(), Box<dyn Error>> {
             "{:?}",
             simplex
                 .vertices()
-                .unwrap()
-                .iter()
                 .map(|v| v.index(&qh).unwrap())
                 .collect::<Vec<_>>()
         );
@@ -21,8 +19,6 @@ fn main() -> Result<(), Box<dyn Error>> {
         .filter(|f| !f.is_sentinel() && !f.upper_delaunay())
         .map(|f| {
             f.vertices()
-                .unwrap()
-                .iter()
                 .map(|v| v.index(&qh).unwrap())
                 .collect::<Vec<_>>()
         })
@@ -14,8 +14,6 @@ fn main() -> Result<(), Box<dyn Error>> {
     for simplex in qh.simplices() {
         let vertices = simplex
             .vertices()
-            .unwrap()
-            .iter()
             .map(|v| v.index(&qh).unwrap())
             .collect::<Vec<_>>();
 
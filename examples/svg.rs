@@ -45,13 +45,13 @@ fn main() {
         eprintln!(
             "- {} -",
             s
-                .vertices().unwrap().iter()
+                .vertices()
                 .map(|v| v.index(&triangulation).unwrap().to_string())
                 .collect::<Vec<_>>()
                 .join(" - "),
         );
         let vertices = s
-            .vertices().unwrap().iter()
+            .vertices()
             .map(|v| v.point().unwrap())
             .collect::<Vec<_>>();
         assert_eq!(vertices.len(), 3);
@@ -71,13 +71,13 @@ fn main() {
         eprintln!(
             "{}",
             s
-                .vertices().unwrap().iter()
+                .vertices()
                 .map(|v| v.index(&convex_hull).unwrap().to_string())
                 .collect::<Vec<_>>()
                 .join(" - "),
         );
         let vertices = s
-            .vertices().unwrap().iter()
+            .vertices()
             .map(|v| v.point().unwrap())
             .collect::<Vec<_>>();
         assert_eq!(vertices.len(), 2);
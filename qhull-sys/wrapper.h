@@ -1 +1,3 @@
-#include <libqhull_r.h>
\ No newline at end of file
+#include <libqhull_r.h>
+#include <geom_r.h>
+#include <poly_r.h>
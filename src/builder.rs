@@ -1,12 +1,40 @@
-use std::{cell::{RefCell, UnsafeCell}, marker::PhantomData, ptr, rc::Rc};
+use std::{cell::{RefCell, UnsafeCell}, ffi::CString, marker::PhantomData, ptr, sync::Arc};
 
 use crate::{
-    helpers::{collect_coords, CollectedCoords},
+    helpers::{collect_coords, prepare_delaunay_points, CollectedCoords},
     io_buffers::IOBuffers,
-    sys, Qh, QhError,
+    sys, Qh, QhError, QhErrorKind,
 };
 
-type QhConfigurator = Box<dyn for<'b> Fn(&'b mut Qh) -> Result<(), QhError<'b>> + 'static>;
+type QhConfigurator = Box<dyn for<'b> Fn(&'b mut Qh) -> Result<(), QhError<'b>> + Send + 'static>;
+
+/// Error of a single build attempt, see [`QhBuilder::joggle_retry`]
+struct BuildError {
+    error: QhError<'static>,
+    /// Whether a new attempt can be made
+    retry: bool,
+}
+
+impl BuildError {
+    fn setup(e: QhError) -> Self {
+        Self { error: e.into_static(), retry: false }
+    }
+}
+
+/// How the hull is derived from the user's points
+///
+/// Used by [`QhBuilder::reset`] to prepare new points like the constructor did.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Construction {
+    /// The points are given to qhull as they are
+    Hull,
+    /// The points are lifted with [`prepare_delaunay_points`]
+    Delaunay,
+    /// Like [`Construction::Delaunay`], and the Voronoi centers are computed
+    /// with [`qh_setvoronoi_all`](sys::qh_setvoronoi_all), and the vertex neighbors
+    /// with [`Qh::compute_vertex_neighbors`]
+    Voronoi,
+}
 
 /// Builder for a Qhull instance
 ///
@@ -34,7 +62,12 @@ pub struct QhBuilder {
     compute: bool,
     check_output: bool,
     check_points: bool,
+    quiet: bool,
+    joggle_retries: u32,
+    points_read_only: bool,
+    flags: Vec<String>,
     configs: Vec<QhConfigurator>,
+    construction: Construction,
 }
 
 /// Default settings:
@@ -51,7 +84,12 @@ impl Default for QhBuilder {
             compute: true,
             check_output: false,
             check_points: false,
+            quiet: false,
+            joggle_retries: 0,
+            points_read_only: false,
+            flags: Vec::new(),
             configs: Vec::new(),
+            construction: Construction::Hull,
         }
     }
 }
@@ -80,6 +118,7 @@ impl QhBuilder {
     /// Capture stderr
     ///
     /// When enabled, the error output of the qhull library will be captured instead of printed to the console.
+    /// With the `log` feature, stderr is always captured and the messages are emitted with the `log` crate.
     pub fn capture_stderr(mut self, capture: bool) -> Self {
         self.capture_stderr = capture;
         self
@@ -127,6 +166,37 @@ impl QhBuilder {
         self
     }
 
+    /// Verify the output after computing the hull
+    ///
+    /// Sets qhull's `VERIFYoutput` (`'Tv'`) and enables [`check_output`](QhBuilder::check_output):
+    /// the `build_*` methods call [`Qh::check_output`] after a successful [`Qh::compute`]
+    /// and return its error, if any, just like a failure of the computation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = QhBuilder::default()
+    ///     .verify_output(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    pub fn verify_output(mut self, yes: bool) -> Self {
+        self = unsafe {
+            self.with_configure(move |qh| {
+                Qh::try_on_qh_mut(qh, |qh| {
+                    (*qh).VERIFYoutput = yes as _;
+                })
+            })
+        };
+        self.check_output(yes)
+    }
+
     /// Set whether to check the points when building the Qhull instance
     ///
     /// When enabled, [`Qh::check_points`] will be called after computing the hull.
@@ -136,6 +206,261 @@ impl QhBuilder {
         self
     }
 
+    /// Suppress all the diagnostic output of qhull
+    ///
+    /// When enabled, both stdout and stderr are [captured](QhBuilder::capture_stderr)
+    /// regardless of the other settings, and the `Pp` option (do not report precision problems) is set.
+    /// The error messages are still written to the captured stderr,
+    /// so that they are available in [`QhError::error_message`] on failure.
+    ///
+    /// With the `log` feature, the messages of qhull are emitted with the `log` crate
+    /// at the debug level; quiet instances emit them at the trace level instead of suppressing them.
+    ///
+    /// Statistics (`Ts`) are not printed unless requested.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // collinear points: the initial simplex is flat
+    /// let error = QhBuilder::default()
+    ///     .quiet(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 1.0],
+    ///         [2.0, 2.0],
+    ///     ])
+    ///     .err()
+    ///     .unwrap();
+    /// assert!(error.error_message.is_some());
+    /// ```
+    pub fn quiet(mut self, yes: bool) -> Self {
+        self.quiet = yes;
+        self
+    }
+
+    /// Set the random seed used by qhull
+    ///
+    /// This is the `qhull QR-n` option: `seed` seeds qhull's random number generator
+    /// (used e.g. for joggling and for the random choices of the construction),
+    /// so that builds with the same seed and input are reproducible.
+    /// Unlike `QRn`, the input is not rotated.
+    /// Different seeds can produce topologically different but geometrically equivalent hulls.
+    ///
+    /// Since `QR-1` and `QR0` use the current time, the seed is mapped to `QR-2`, `QR-3`, ...
+    /// (modulo the range of the option), any `seed` is valid.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = (0..50).map(|i| {
+    ///     let t = i as f64;
+    ///     [t.sin(), (2.0 * t).cos(), (3.0 * t).sin()]
+    /// });
+    /// let faces = |seed| {
+    ///     let qh = QhBuilder::default()
+    ///         .seed(seed)
+    ///         .build_from_iter(points.clone())
+    ///         .unwrap();
+    ///     qh.facets()
+    ///         .map(|f| f.vertices().map(|v| v.index(&qh).unwrap()).collect::<Vec<_>>())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(faces(42), faces(42));
+    ///
+    /// // the input is not rotated
+    /// let qh = QhBuilder::default()
+    ///     .seed(0)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(qh.is_point_inside(&[0.5, 0.5]).unwrap());
+    /// assert!(qh.vertices().all(|v| v.point().unwrap().iter().all(|&x| x == 0.0 || x == 1.0)));
+    /// ```
+    pub fn seed(self, seed: u32) -> Self {
+        // `QR-n` with `n` in `2..=i32::MAX`
+        let n = seed as i64 % (i32::MAX as i64 - 1) + 2;
+        self.with_flags(&format!("QR-{n}"))
+    }
+
+    /// Joggle the input points
+    ///
+    /// This is the `qhull QJn` option: the input points are randomly perturbed by
+    /// at most `amplitude` in each coordinate, which avoids precision problems
+    /// with degenerate input (e.g. coplanar or collinear points) at the cost of an approximate
+    /// output: all the facets are simplicial and the points are not exactly on the hull.
+    ///
+    /// * `None` does not joggle the input (the default)
+    /// * `Some(0.0)` lets qhull choose the amplitude from the input
+    ///
+    /// The option is added to the [flags](QhBuilder::with_flags), so it cannot be removed
+    /// by a later call with `None`.
+    ///
+    /// On precision errors, qhull retries itself with increasing amplitudes
+    /// (up to `qh_JOGGLEmaxretry` times, see `user_r.h`),
+    /// see also [`joggle_retry`](QhBuilder::joggle_retry).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: the faces are squares
+    /// let cube = [
+    ///     [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    /// ];
+    ///
+    /// let qh = QhBuilder::default().build_from_iter(cube).unwrap();
+    /// assert_eq!(qh.num_facets(), 6);
+    ///
+    /// // joggled: every facet is a triangle
+    /// let qh = QhBuilder::default()
+    ///     .joggle(Some(1e-6))
+    ///     .build_from_iter(cube)
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 12);
+    /// assert!(qh.facets().all(|f| f.simplicial()));
+    /// assert_eq!(qh.hull_option_string(), "QJ0.000001");
+    ///
+    /// // the output is already simplicial
+    /// assert!(QhBuilder::default().joggle(Some(0.0)).triangulate(true).validate().is_err());
+    /// ```
+    pub fn joggle(self, amplitude: Option<f64>) -> Self {
+        match amplitude {
+            Some(amplitude) => self.with_flags(&format!("QJ{amplitude}")),
+            None => self,
+        }
+    }
+
+    /// Number of times a failed joggled build is retried
+    ///
+    /// When the computation of a [joggled](QhBuilder::joggle) hull fails
+    /// (after the retries made by qhull itself),
+    /// the whole build is restarted with a different random seed up to `count` times.
+    /// The error of the last attempt is returned if all of them fail.
+    ///
+    /// The default is `0`, this setting has no effect if joggling is disabled.
+    pub fn joggle_retry(mut self, count: u32) -> Self {
+        self.joggle_retries = count;
+        self
+    }
+
+    /// Set the minimum distance of a point above a facet for the facet to be visible
+    ///
+    /// This is the `qhull Vn` option: points closer than `tol` to a facet are considered coplanar with it.
+    /// By default, the tolerance is inferred from the precision of the input.
+    ///
+    /// See [`centrum_radius`](QhBuilder::centrum_radius) for an example.
+    pub fn coplanar_tolerance(self, tol: f64) -> Self {
+        self.with_flags(&format!("V{tol}"))
+    }
+
+    /// Set the centrum radius for merging facets
+    ///
+    /// This is the `qhull Cn` option: after the hull is built, adjacent facets are merged
+    /// when the centrum of one of them is less than `radius` below the other (i.e. they are not clearly convex).
+    /// By default, the radius is inferred from the precision of the input.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a noisy sampling of the faces of the unit cube
+    /// let mut points = vec![];
+    /// for i in 0..=4 {
+    ///     for j in 0..=4 {
+    ///         let (u, v) = (i as f64 / 4.0, j as f64 / 4.0);
+    ///         let noise = 1e-4 * ((7 * i + 13 * j) % 5) as f64;
+    ///         for w in [-noise, 1.0 + noise] {
+    ///             points.push([u, v, w]);
+    ///             points.push([v, w, u]);
+    ///             points.push([w, u, v]);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let qh = QhBuilder::default()
+    ///     .build_from_iter(points.clone())
+    ///     .unwrap();
+    /// assert!(qh.num_facets() > 6);
+    ///
+    /// // the noise is below the tolerance, the faces of the cube are recovered
+    /// let qh = QhBuilder::default()
+    ///     .centrum_radius(1e-2)
+    ///     .coplanar_tolerance(1e-2)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 6);
+    /// ```
+    pub fn centrum_radius(self, radius: f64) -> Self {
+        self.with_flags(&format!("C{radius}"))
+    }
+
+    /// Pass raw options to qhull
+    ///
+    /// The options use the same syntax as the `qhull` command-line tool
+    /// (e.g. `"Qt QJ0.01 C-0"`) and are parsed by [`qh_initflags`](sys::qh_initflags).
+    /// Multiple calls are concatenated.
+    ///
+    /// The options are applied before the settings of the other builder methods,
+    /// which therefore override the corresponding options.
+    /// Conflicting options inside the raw strings are resolved by qhull's own flag parser.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // scale the input to the unit box [-0.5, 0.5]^d
+    /// let qh = QhBuilder::default()
+    ///     .with_flags("QbB")
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [10.0, 0.0],
+    ///         [0.0, 10.0],
+    ///         [2.5, 2.5],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(qh.num_facets(), 3);
+    /// for vertex in qh.vertices() {
+    ///     for x in vertex.point().unwrap() {
+    ///         assert!(x.abs() <= 0.5 + 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn with_flags(mut self, flags: &str) -> Self {
+        self.flags.push(flags.to_string());
+        self
+    }
+
+    /// Remove the last options given with [`QhBuilder::with_flags`]
+    pub(crate) fn pop_flags(&mut self) {
+        self.flags.pop();
+    }
+
+    /// Set how the hull is derived from the user's points, see [`Construction`]
+    pub(crate) fn construction(mut self, construction: Construction) -> Self {
+        self.construction = construction;
+        self
+    }
+
+    /// Check the settings for known conflicting options
+    ///
+    /// Returns a description of the first conflict found, e.g. Delaunay triangulation
+    /// together with halfspace intersection, or furthest-site Delaunay (`Qu`) without Delaunay.
+    /// The `build_*` methods perform the same check before passing the points to qhull.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// assert!(QhBuilder::default().delaunay(true).validate().is_ok());
+    ///
+    /// let builder = QhBuilder::default()
+    ///     .delaunay(true)
+    ///     .half_space(true);
+    /// assert!(builder.validate().is_err());
+    /// assert!(builder.build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let qh = unsafe { self.init(self.dim.unwrap_or(0)) }.map_err(|e| e.to_string())?;
+        check_conflicts(&qh)
+    }
+
     /// Build a Qhull instance
     ///
     /// # Example
@@ -159,6 +484,20 @@ impl QhBuilder {
     /// * If the dimensionality of the points does not match the hint
     /// * Cannot create a temporary file for capturing stdout or stderr
     pub fn build(self, dim: usize, points: &mut [f64]) -> Result<Qh, QhError> {
+        unsafe { self.build_raw(dim, points.as_mut_ptr(), points.len()) }
+    }
+
+    /// Implementation of [`QhBuilder::build`]
+    ///
+    /// # Safety
+    /// * `points` must point to `len` coordinates that outlive the instance
+    /// * qhull may write to the coordinates unless `points_read_only` is set
+    unsafe fn build_raw<'a>(
+        self,
+        dim: usize,
+        points: *mut f64,
+        len: usize,
+    ) -> Result<Qh<'a>, QhError<'static>> {
         if let Some(dim_hint) = self.dim {
             assert_eq!(
                 dim, dim_hint,
@@ -166,12 +505,36 @@ impl QhBuilder {
             );
         }
 
-        assert_eq!(points.len() % dim, 0, "points.len() % dim != 0");
-        let num_points = points.len() / dim;
+        assert_eq!(len % dim, 0, "points.len() % dim != 0");
+        let num_points = len / dim;
+
+        let mut attempt = 0;
+        loop {
+            match unsafe { self.build_attempt(dim, points, num_points, attempt) } {
+                Err(BuildError { retry: true, .. }) if attempt < self.joggle_retries => attempt += 1,
+                Err(BuildError { error, .. }) => return Err(error),
+                Ok(mut qh) => {
+                    qh.builder = Some(self);
+                    return Ok(qh);
+                }
+            }
+        }
+    }
 
+    /// Initialize a Qhull instance and apply the settings, without the points
+    unsafe fn init<'a>(&self, dim: usize) -> Result<Qh<'a>, QhError<'static>> {
         unsafe {
             let mut qh: sys::qhT = std::mem::zeroed();
-            let buffers = IOBuffers::new(self.capture_stdout, self.capture_stderr);
+            #[allow(unused_mut)]
+            // with the `log` feature the messages are read from the captured stderr
+            let mut buffers = IOBuffers::new(
+                self.capture_stdout || self.quiet,
+                self.capture_stderr || self.quiet || cfg!(feature = "log"),
+            );
+            #[cfg(feature = "log")]
+            if self.quiet {
+                buffers.log_level = log::Level::Trace;
+            }
 
             // Note: this function cannot be called
             // inside of a try
@@ -190,35 +553,178 @@ impl QhBuilder {
                 dim,
                 buffers: RefCell::new(buffers),
                 owned_values: Default::default(),
+                builder: None,
+                added_points: Vec::new(),
+                option_string: String::new(),
                 phantom: PhantomData,
             };
+            self.configure(&mut qh)?;
+            Ok(qh)
+        }
+    }
+
+    /// Apply the flags and the settings to an initialized instance
+    unsafe fn configure(&self, qh: &mut Qh) -> Result<(), QhError<'static>> {
+        unsafe {
+            let mut flags = self.flags.clone();
+            // with the `log` feature the precision warnings are logged at the trace level instead
+            if self.quiet && !cfg!(feature = "log") {
+                flags.push("Pp".to_string());
+            }
+            qh.option_string = flags.join(" ");
+            if !flags.is_empty() {
+                // qh_initflags skips the first word (the program name)
+                let command = CString::new(format!("qhull {}", qh.option_string))
+                    .expect("qhull flags must not contain nul characters");
+                Qh::try_on_qh_mut(qh, |qh| {
+                    sys::qh_initflags(qh, command.as_ptr() as *mut _);
+                })
+                .map_err(|e| e.into_static())?;
+            }
+
+            for config in &self.configs {
+                config(qh).map_err(|e| e.into_static())?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// A single attempt of [`QhBuilder::build`]
+    ///
+    /// Retries (`attempt > 0`) of a joggled computation use a different random seed.
+    ///
+    /// # Safety
+    /// * `points` must point to `num_points * dim` coordinates that outlive the instance
+    unsafe fn build_attempt<'a>(
+        &self,
+        dim: usize,
+        points: *mut f64,
+        num_points: usize,
+        attempt: u32,
+    ) -> Result<Qh<'a>, BuildError> {
+        unsafe {
+            let mut qh = self.init(dim).map_err(BuildError::setup)?;
+            self.set_points(&mut qh, dim, points, num_points, attempt)?;
+            Ok(qh)
+        }
+    }
+
+    /// Give the points to a configured instance and compute the hull (if enabled)
+    ///
+    /// # Safety
+    /// * `points` must point to `num_points * dim` coordinates that outlive the instance
+    unsafe fn set_points(
+        &self,
+        qh: &mut Qh,
+        dim: usize,
+        points: *mut f64,
+        num_points: usize,
+        attempt: u32,
+    ) -> Result<(), BuildError> {
+        unsafe {
+            check_conflicts(qh)
+                .map_err(|message| BuildError::setup(QhError::from_message(QhErrorKind::Input, message)))?;
+            // see qh_initbuild: the last coordinate is scaled in place
+            if self.points_read_only && (*qh.qh.get()).SCALElast != 0 {
+                return Err(BuildError::setup(QhError::from_message(
+                    QhErrorKind::Input,
+                    "scaling the last coordinate ('Qbb') modifies the points, they cannot be borrowed".to_string(),
+                )));
+            }
 
-            for config in self.configs {
-                config(&mut qh).map_err(|e| e.into_static())?;
+            if attempt > 0 {
+                let qh = qh.qh.get_mut();
+                let attempt = attempt as i32;
+                // see qh_initqhull_globals: a seed n > 0 also rotates the input,
+                // n < -1 only seeds the random number generator, 0 and -1 use the time
+                qh.ROTATErandom = match qh.ROTATErandom {
+                    i32::MIN => -1 - attempt,
+                    seed if seed > 0 => seed.saturating_add(attempt),
+                    seed if seed < -1 => seed.saturating_sub(attempt),
+                    seed => seed,
+                };
             }
 
-            Qh::try_on_qh_mut(&mut qh, |qh| {
+            Qh::try_on_qh_mut(qh, |qh| {
                 sys::qh_init_B(
                     qh,
-                    points.as_ptr() as *mut f64,
+                    points,
                     num_points as _,
                     dim as _,
                     false as _,
                 );
             })
-            .map_err(|e| e.into_static())?;
+            .map_err(BuildError::setup)?;
 
             if self.compute {
-                qh.compute().map_err(|e| e.into_static())?;
+                let retry = (*qh.qh.get()).JOGGLEmax < f64::MAX / 2.0;
+                let compute_error = |e: QhError| BuildError { error: e.into_static(), retry };
+                qh.compute().map_err(compute_error)?;
                 if self.check_output {
-                    qh.check_output().map_err(|e| e.into_static())?;
+                    qh.check_output().map_err(compute_error)?;
                 }
                 if self.check_points {
-                    qh.check_points().map_err(|e| e.into_static())?;
+                    qh.check_points().map_err(compute_error)?;
+                }
+                if self.construction == Construction::Voronoi {
+                    Qh::try_on_qh_mut(qh, |qh| sys::qh_setvoronoi_all(qh))
+                        .map_err(BuildError::setup)?;
+                    // the Voronoi cells are read from the faces of each vertex
+                    qh.compute_vertex_neighbors().map_err(BuildError::setup)?;
                 }
             }
 
-            Ok(qh)
+            Ok(())
+        }
+    }
+
+    /// Replace the points of an instance built with these settings
+    ///
+    /// See [`Qh::reset_with_points`].
+    pub(crate) fn reset(&self, qh: &mut Qh, dim: usize, points: Vec<f64>) -> Result<(), QhError<'static>> {
+        check_flat_coords(dim, points.len())?;
+        let (dim, mut points) = match self.construction {
+            Construction::Hull => (dim, points),
+            Construction::Delaunay | Construction::Voronoi => {
+                if points.is_empty() {
+                    return Err(QhError::from_message(QhErrorKind::Input, "no points"));
+                }
+                let CollectedCoords { coords, dim, .. } =
+                    prepare_delaunay_points(points.chunks(dim).map(|point| point.iter().copied()));
+                (dim, coords)
+            }
+        };
+        if self.dim.is_some_and(|hint| hint != dim) {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "data dimensionality does not match hint that was given with QhBuilder::dim",
+            ));
+        }
+        unsafe {
+            // free the hull but keep the memory pools (`qh.qhmem`) for the new computation,
+            // the rest of `qhT` is cleared like in `qh_init_A`
+            sys::qh_freeqhull(qh.qh.get_mut(), sys::qh_ALL);
+            qh.coords_holder = None;
+            qh.added_points.clear();
+            qh.owned_values = Default::default();
+            qh.dim = dim;
+            {
+                let buffers = qh.buffers.borrow();
+                sys::qh_initqhull_start(
+                    qh.qh.get_mut(),
+                    buffers.in_file(),
+                    buffers.out_file(),
+                    buffers.err_file(),
+                );
+            }
+            self.configure(qh)?;
+
+            let num_points = points.len() / dim;
+            let points_ptr = points.as_mut_ptr();
+            qh.coords_holder = Some(points);
+            self.set_points(qh, dim, points_ptr, num_points, 0)
+                .map_err(|e| e.error)
         }
     }
 
@@ -286,8 +792,79 @@ impl QhBuilder {
         self.build_managed(dim, coords)
     }
 
+    /// Build a Qhull instance from a flat array of coordinates
+    ///
+    /// The coordinates are in row-major order (all the coordinates of the first point,
+    /// then all the coordinates of the second point, etc.) and are owned by the instance,
+    /// like in [`QhBuilder::build_managed`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = QhBuilder::default()
+    ///     .build_from_flat_slice(2, vec![
+    ///         0.0, 0.0,
+    ///         1.0, 0.0,
+    ///         0.0, 1.0,
+    ///         0.25, 0.25,
+    ///     ]).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// // incomplete point
+    /// assert!(QhBuilder::default().build_from_flat_slice(2, vec![0.0, 0.0, 1.0]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// * If `dim` is zero or the number of coordinates is not divisible by `dim`
+    pub fn build_from_flat_slice(
+        self,
+        dim: usize,
+        coords: Vec<f64>,
+    ) -> Result<Qh<'static>, QhError<'static>> {
+        check_flat_coords(dim, coords.len())?;
+        self.build_managed(dim, coords)
+    }
+
+    /// Build a Qhull instance borrowing a flat array of coordinates
+    ///
+    /// Same as [`QhBuilder::build_from_flat_slice`], but the coordinates are passed
+    /// to qhull without copying them.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let coords = vec![
+    ///     0.0, 0.0,
+    ///     1.0, 0.0,
+    ///     0.0, 1.0,
+    ///     0.25, 0.25,
+    /// ];
+    /// let qh = QhBuilder::default()
+    ///     .build_from_flat_slice_ref(2, &coords)
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    ///
+    /// # Errors
+    /// * If `dim` is zero or the number of coordinates is not divisible by `dim`
+    /// * If the settings require qhull to modify the points in place
+    ///   (scaling the last coordinate, [`QhBuilder::scale_last`])
+    pub fn build_from_flat_slice_ref<'a>(
+        mut self,
+        dim: usize,
+        coords: &'a [f64],
+    ) -> Result<Qh<'a>, QhError<'a>> {
+        check_flat_coords(dim, coords.len())?;
+        self.points_read_only = true;
+        // qhull only reads the points, see `points_read_only`
+        unsafe { self.build_raw(dim, coords.as_ptr() as *mut f64, coords.len()) }
+    }
+
     /// Configure the qhull instance with a closure
     ///
+    /// The closure is kept by the instance for [`Qh::reset_with_points`],
+    /// it must be [`Send`] since the instance can be moved to another thread with [`Qh::into_send`].
+    ///
     /// # Safety
     /// * closure must not panic
     /// * closure shall not invalidate the qhull instance
@@ -309,7 +886,7 @@ impl QhBuilder {
     /// ```
     pub unsafe fn with_configure(
         mut self,
-        configurator: impl for<'a> Fn(&'a mut Qh) -> Result<(), QhError<'a>> + 'static,
+        configurator: impl for<'a> Fn(&'a mut Qh) -> Result<(), QhError<'a>> + Send + 'static,
     ) -> Self {
         self.configs.push(Box::new(configurator));
         self
@@ -320,6 +897,57 @@ impl QhBuilder {
 
 // https://doc.rust-lang.org/book/ch03-02-data-types.html
 
+/// Check the layout of a flat array of coordinates
+fn check_flat_coords(dim: usize, len: usize) -> Result<(), QhError<'static>> {
+    if dim == 0 || !len.is_multiple_of(dim) {
+        return Err(QhError::from_message(
+            QhErrorKind::Input,
+            format!("{len} coordinates cannot be split into points of dimension {dim}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Known combinations of options that qhull rejects or that are not meaningful
+fn check_conflicts(qh: &Qh) -> Result<(), String> {
+    let qh = unsafe { &*qh.qh.get() };
+    let conflicts = [
+        (
+            qh.VORONOI != 0 && qh.DELAUNAY == 0,
+            "Voronoi diagram ('v') requires Delaunay triangulation ('d')",
+        ),
+        (
+            qh.DELAUNAY != 0 && qh.HALFspace != 0,
+            "Delaunay triangulation ('d') or Voronoi diagram ('v') cannot be used with halfspace intersection ('H')",
+        ),
+        (
+            qh.DELAUNAY == 0 && qh.UPPERdelaunay != 0,
+            "furthest-site Delaunay ('Qu') requires Delaunay triangulation ('d') or Voronoi diagram ('v')",
+        ),
+        (
+            qh.DELAUNAY == 0 && qh.ATinfinity != 0,
+            "point at infinity ('Qz') requires Delaunay triangulation ('d') or Voronoi diagram ('v')",
+        ),
+        (
+            qh.UPPERdelaunay != 0 && qh.ATinfinity != 0,
+            "point at infinity ('Qz') cannot be used with furthest-site Delaunay ('Qu')",
+        ),
+        (
+            qh.TRIangulate != 0 && qh.JOGGLEmax < f64::MAX / 2.0 && qh.PREmerge == 0 && qh.POSTmerge == 0,
+            "triangulated output ('Qt') has no effect with joggled input ('QJ'), which is already simplicial",
+        ),
+        (
+            qh.MERGEpinched != 0 && qh.ONLYgood != 0,
+            "merging pinched vertices ('Q14') cannot be used with good facets only ('Qg')",
+        ),
+    ];
+
+    match conflicts.into_iter().find(|(conflict, _)| *conflict) {
+        Some((_, message)) => Err(format!("conflicting options: {message}")),
+        None => Ok(()),
+    }
+}
+
 macro_rules! add_setting {
     ($(
         $(#[$meta:meta])*
@@ -406,7 +1034,7 @@ macro_rules! add_setting {
         $(#[$meta])*
         #[doc = add_setting!(safety documentation: unsafe)]
         pub unsafe fn $setter(mut self, $setter: impl IntoIterator<Item = type_mapping::$ty>) -> Self {
-            let $setter = Rc::new($setter.into_iter().collect::<Vec<_>>());
+            let $setter = Arc::new($setter.into_iter().collect::<Vec<_>>());
             self = unsafe {
                 self.with_configure(move |qh| {
                     let ptr = $setter.as_ptr();
@@ -428,7 +1056,7 @@ macro_rules! add_setting {
         #[doc = add_setting!(safety documentation: $($unsafe)?)]
         pub $($unsafe)? fn $setter(mut self, $setter: impl IntoIterator<Item = type_mapping::$ty>) -> Self {
             let dim = self.dim.expect(concat!("dimension hint is required for ", stringify!($setter), " setter"));
-            let $setter = Rc::new($setter.into_iter().collect::<Vec<_>>());
+            let $setter = Arc::new($setter.into_iter().collect::<Vec<_>>());
             assert_eq!($setter.len() % dim, 0, concat!("number of elements in ", stringify!($setter), " must be divisible by dim"));
             self = unsafe {
                 self.with_configure(move |qh| {
@@ -545,7 +1173,6 @@ add_setting!(
     scalar(boolT) tri_normals => TRInormals "true 'Q11' if triangulate duplicates ->normal and ->center (sets Qt)",
     scalar(boolT) upper_delaunay => UPPERdelaunay "true 'Qu' if computing furthest-site Delaunay",
     scalar(boolT) use_stdout => USEstdout "true 'Tz' if using stdout instead of stderr",
-    scalar(boolT) verify_output => VERIFYoutput "true 'Tv' if verify output at end of qhull",
     scalar(boolT) virtual_memory => VIRTUALmemory "true 'Q7' if depth-first processing in buildhull",
     scalar(boolT) voronoi => VORONOI "true 'v' if computing Voronoi diagram, also sets qh.DELAUNAY",
 
@@ -580,7 +1207,6 @@ add_setting!(
 
     /* precision constants */
     scalar(realT) angle_round => ANGLEround "max round off error for angles",
-    scalar(realT) centrum_radius => centrum_radius "max centrum radius for convexity ('Cn' + 2*qh.DISTround)",
     scalar(realT) cos_max => cos_max "max cosine for convexity (roundoff added)",
     scalar(realT) dist_round => DISTround "max round off error for distances, qh.SETroundoff ('En') overrides qh_distround",
     scalar(realT) max_abs_coors => MAXabs_coord "max absolute coordinate",
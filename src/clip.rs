@@ -0,0 +1,199 @@
+//! Clipping of the hull by halfspaces
+
+use crate::{Facet, Qh, QhBuilder, QhError, QhErrorKind};
+
+impl<'a> Qh<'a> {
+    /// Clip the hull by the halfspace `{x : normal · x <= offset}`
+    ///
+    /// The vertices of the clipped hull are the vertices of the hull inside the halfspace
+    /// and the intersections of the boundary of the halfspace with the edges of the hull.
+    /// They are found with the Sutherland–Hodgman algorithm on each face:
+    /// the polygon of a 3-d face, or the edges of a simplicial face, is clipped by the halfspace.
+    /// Non-simplicial faces in other dimensions are clipped along all the segments between
+    /// their vertices, which gives the same hull.
+    /// The clipped hull is then computed again with the default settings.
+    ///
+    /// Note that the convention for the offset is the one of [`Halfspace`](crate::Halfspace),
+    /// not qhull's.
+    ///
+    /// # Errors
+    /// * If `normal.len()` is not the dimension of the hull
+    /// * If qhull fails to compute the clipped hull,
+    ///   e.g. because the halfspace does not intersect the interior of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // the unit cube
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// // cut by a diagonal plane through the center: the cut is a regular hexagon
+    /// let mut clipped = qh.intersection_with_halfspace(&[1.0, 1.0, 1.0], 1.5).unwrap();
+    /// assert_eq!(clipped.num_vertices(), 4 + 6);
+    /// assert_eq!(clipped.num_facets(), 3 + 3 + 1);
+    /// assert!((clipped.volume().unwrap() - 0.5).abs() < 1e-12);
+    ///
+    /// // the whole cube is inside
+    /// let clipped = qh.intersection_with_halfspace(&[1.0, 0.0, 0.0], 2.0).unwrap();
+    /// assert_eq!(clipped.num_vertices(), 8);
+    ///
+    /// // no intersection
+    /// assert!(qh.intersection_with_halfspace(&[1.0, 0.0, 0.0], -1.0).is_err());
+    /// ```
+    pub fn intersection_with_halfspace(
+        &self,
+        normal: &[f64],
+        offset: f64,
+    ) -> Result<Qh<'static>, QhError<'static>> {
+        if normal.len() != self.dim {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("the normal has {} coordinates, expected {}", normal.len(), self.dim),
+            ));
+        }
+        let distance = |p: &[f64]| normal.iter().zip(p).map(|(n, x)| n * x).sum::<f64>() - offset;
+
+        let mut coords = Vec::new();
+        for vertex in self.vertices() {
+            if let Some(point) = vertex.point().filter(|p| distance(p) <= 0.0) {
+                coords.extend_from_slice(point);
+            }
+        }
+        for facet in self.facets() {
+            for (a, b) in face_edges(&facet) {
+                let (da, db) = (distance(a), distance(b));
+                if (da <= 0.0) != (db <= 0.0) {
+                    let t = da / (da - db);
+                    coords.extend(a.iter().zip(b).map(|(a, b)| a + t * (b - a)));
+                }
+            }
+        }
+
+        QhBuilder::default().build_from_flat_slice(self.dim, coords)
+    }
+
+    /// The polygon cut by the plane `normal · x = offset` in a 3-d hull
+    ///
+    /// The vertices of the polygon are the intersections of the plane with the edges of the hull
+    /// (and the vertices of the hull on the plane), in counter-clockwise order seen from
+    /// the side the normal points to.
+    ///
+    /// Returns `None` if the hull is not 3-d or if the plane does not cut the interior of the hull
+    /// (the section has fewer than 3 vertices).
+    ///
+    /// # Panics
+    /// * If `normal.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // the unit cube
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// // the square at z = 0.5
+    /// let section = qh.cross_section(&[0.0, 0.0, 1.0], 0.5).unwrap();
+    /// assert_eq!(section.len(), 4);
+    /// assert!(section.iter().all(|p| (p[2] - 0.5).abs() < 1e-12));
+    ///
+    /// // counter-clockwise seen from above
+    /// let area = (0..4)
+    ///     .map(|i| {
+    ///         let (p, q) = (&section[i], &section[(i + 1) % 4]);
+    ///         p[0] * q[1] - p[1] * q[0]
+    ///     })
+    ///     .sum::<f64>() / 2.0;
+    /// assert!((area - 1.0).abs() < 1e-12);
+    ///
+    /// // a regular hexagon through the center
+    /// assert_eq!(qh.cross_section(&[1.0, 1.0, 1.0], 1.5).unwrap().len(), 6);
+    ///
+    /// assert!(qh.cross_section(&[0.0, 0.0, 1.0], 2.0).is_none());
+    /// ```
+    pub fn cross_section(&self, normal: &[f64], offset: f64) -> Option<Vec<Vec<f64>>> {
+        assert_eq!(normal.len(), self.dim, "normal and hull have different dimensions");
+        if self.dim != 3 {
+            return None;
+        }
+        let distance = |p: &[f64]| normal.iter().zip(p).map(|(n, x)| n * x).sum::<f64>() - offset;
+
+        let mut points: Vec<[f64; 3]> = Vec::new();
+        for vertex in self.vertices() {
+            if let Some(point) = vertex.point().filter(|p| distance(p) == 0.0) {
+                points.push([point[0], point[1], point[2]]);
+            }
+        }
+        for facet in self.facets() {
+            for (a, b) in face_edges(&facet) {
+                let (da, db) = (distance(a), distance(b));
+                if da * db < 0.0 {
+                    // the same orientation for the two faces of the edge gives the same point
+                    let (a, b, da, db) = if da < 0.0 { (a, b, da, db) } else { (b, a, db, da) };
+                    let t = da / (da - db);
+                    points.push(std::array::from_fn(|i| a[i] + t * (b[i] - a[i])));
+                }
+            }
+        }
+        if points.len() < 3 {
+            return None;
+        }
+
+        // sort by angle in the plane, with the basis (u, v, normal) right-handed
+        let n = points.len() as f64;
+        let center: [f64; 3] = std::array::from_fn(|i| points.iter().map(|p| p[i]).sum::<f64>() / n);
+        let axis = (0..3).min_by(|&i, &j| normal[i].abs().total_cmp(&normal[j].abs())).unwrap();
+        let mut e = [0.0; 3];
+        e[axis] = 1.0;
+        let cross = |a: [f64; 3], b: [f64; 3]| {
+            [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+        };
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        let normal = [normal[0], normal[1], normal[2]];
+        let u = cross(e, normal);
+        let v = cross(normal, u);
+        let angle = |p: &[f64; 3]| {
+            let d = std::array::from_fn(|i| p[i] - center[i]);
+            dot(d, v).atan2(dot(d, u))
+        };
+        points.sort_by(|p, q| angle(p).total_cmp(&angle(q)));
+
+        // the points of an edge are computed twice (once for each face),
+        // others only differ by rounding errors, e.g. near a vertex on the plane
+        let extent = points.iter().flatten().fold(0.0f64, |m, x| m.max(x.abs()));
+        let tol = 1e-12 * extent.max(1.0);
+        let close = |p: &[f64; 3], q: &[f64; 3]| p.iter().zip(q).all(|(a, b)| (a - b).abs() <= tol);
+        points.dedup_by(|p, q| close(p, q));
+        while points.len() > 1 && close(&points[0], &points[points.len() - 1]) {
+            points.pop();
+        }
+
+        (points.len() >= 3).then(|| points.into_iter().map(|p| p.to_vec()).collect())
+    }
+}
+
+/// Segments of a face to clip, see [`Qh::intersection_with_halfspace`] and [`Qh::cross_section`]
+///
+/// The sides of the polygon of a 3-d face, otherwise the segments between all the vertices,
+/// which are the edges of a simplicial face.
+fn face_edges<'a>(facet: &Facet<'a>) -> Vec<(&'a [f64], &'a [f64])> {
+    if let Some(polygon) = facet.ordered_vertices_3d() {
+        return (0..polygon.len())
+            .map(|i| (polygon[i].1, polygon[(i + 1) % polygon.len()].1))
+            .collect();
+    }
+    let points: Vec<&[f64]> = facet.vertices().filter_map(|v| v.point()).collect();
+    let mut edges = Vec::new();
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            edges.push((*a, *b));
+        }
+    }
+    edges
+}
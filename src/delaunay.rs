@@ -0,0 +1,291 @@
+//! Delaunay triangulation queries
+
+use std::collections::BTreeSet;
+
+use crate::{Qh, QhError, QhErrorKind};
+
+impl<'a> Qh<'a> {
+    /// Triangles of a 2-d Delaunay triangulation
+    ///
+    /// Each triangle is given by the indices of its vertices in the input points,
+    /// in counter-clockwise order (seen from above).
+    /// The upper Delaunay facets are skipped.
+    ///
+    /// # Errors
+    /// * If the hull was not computed in Delaunay mode (see [`Qh::new_delaunay`])
+    ///   or the triangulated points are not 2-d
+    /// * If a Delaunay facet is not a triangle (e.g. for cocircular input points)
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [2.0, 0.0],
+    ///     [0.0, 2.0],
+    ///     [2.0, 2.5],
+    ///     [0.5, 0.5],
+    /// ];
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// let mut triangles = qh.delaunay_triangles_2d().unwrap();
+    /// for [a, b, c] in &triangles {
+    ///     let (a, b, c) = (points[*a], points[*b], points[*c]);
+    ///     let area = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+    ///     assert!(area > 0.0);
+    /// }
+    ///
+    /// triangles.iter_mut().for_each(|t| t.sort());
+    /// triangles.sort();
+    /// // the inner point is connected to the four corners
+    /// assert_eq!(triangles, vec![[0, 1, 4], [0, 2, 4], [1, 3, 4], [2, 3, 4]]);
+    ///
+    /// // not a Delaunay triangulation
+    /// let qh = Qh::builder().build_from_iter(points).unwrap();
+    /// assert!(qh.delaunay_triangles_2d().is_err());
+    /// ```
+    pub fn delaunay_triangles_2d(&self) -> Result<Vec<[usize; 3]>, QhError<'static>> {
+        let mut triangles = self.delaunay_simplices::<3>()?;
+        for triangle in &mut triangles {
+            let [a, b, c] = triangle.map(|i| self.get_input_point(i).expect("vertex is an input point"));
+            let area = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+            if area < 0.0 {
+                triangle.swap(1, 2);
+            }
+        }
+        Ok(triangles)
+    }
+
+    /// Tetrahedra of a 3-d Delaunay triangulation
+    ///
+    /// Each tetrahedron is given by the indices of its vertices in the input points.
+    /// The upper Delaunay facets are skipped.
+    ///
+    /// # Errors
+    /// * If the hull was not computed in Delaunay mode (see [`Qh::new_delaunay`])
+    ///   or the triangulated points are not 3-d
+    /// * If a Delaunay facet is not a tetrahedron (e.g. for cospherical input points)
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0, 0.0],
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    ///     [0.2, 0.2, 0.2],
+    /// ]).unwrap();
+    ///
+    /// let mut tetrahedra = qh.delaunay_tetrahedra_3d().unwrap();
+    /// tetrahedra.iter_mut().for_each(|t| t.sort());
+    /// tetrahedra.sort();
+    /// // the inner point splits the tetrahedron in four
+    /// assert_eq!(tetrahedra, vec![[0, 1, 2, 4], [0, 1, 3, 4], [0, 2, 3, 4], [1, 2, 3, 4]]);
+    ///
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]]).unwrap();
+    /// assert!(qh.delaunay_tetrahedra_3d().is_err());
+    /// ```
+    pub fn delaunay_tetrahedra_3d(&self) -> Result<Vec<[usize; 4]>, QhError<'static>> {
+        self.delaunay_simplices::<4>()
+    }
+
+    /// Delaunay neighbors of each input point
+    ///
+    /// Returns, for each of the [`Qh::num_input_points`] input points,
+    /// the sorted indices of the points connected to it by an edge of the Delaunay triangulation.
+    /// Points that are not vertices of the triangulation have no neighbors.
+    ///
+    /// # Remarks
+    /// * the lists are empty if the hull was not computed in Delaunay mode,
+    ///   see [`Qh::new_delaunay`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [2.0, 0.0],
+    ///     [0.0, 2.0],
+    ///     [2.0, 2.5],
+    ///     [0.5, 0.5],
+    ///     [0.5, 0.5], // duplicate, not a vertex
+    /// ]).unwrap();
+    ///
+    /// let neighbors = qh.delaunay_neighbors();
+    /// assert_eq!(neighbors[0], vec![1, 2, 4]);
+    /// assert_eq!(neighbors[3], vec![1, 2, 4]);
+    /// assert_eq!(neighbors[4], vec![0, 1, 2, 3]);
+    /// assert!(neighbors[5].is_empty());
+    /// ```
+    pub fn delaunay_neighbors(&self) -> Vec<Vec<usize>> {
+        let mut neighbors = vec![Vec::new(); self.num_input_points()];
+        for [a, b] in self.delaunay_edge_set() {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+        }
+        neighbors
+    }
+
+    /// Edges of the Delaunay triangulation
+    ///
+    /// Each edge is given once, as the `[min, max]` pair of the indices of its endpoints
+    /// in the input points, and the edges are sorted.
+    ///
+    /// # Remarks
+    /// * the list is empty if the hull was not computed in Delaunay mode,
+    ///   see [`Qh::new_delaunay`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use rand::{rngs::StdRng, Rng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let points: Vec<[f64; 2]> = (0..100).map(|_| [rng.gen(), rng.gen()]).collect();
+    ///
+    /// let qh = Qh::new_delaunay(points.iter().copied()).unwrap();
+    /// let edges = qh.delaunay_edges();
+    /// assert!(edges.iter().all(|[a, b]| a < b));
+    /// assert!(edges.windows(2).all(|w| w[0] < w[1]));
+    ///
+    /// // Euler's formula for a triangulation with `h` points on the convex hull
+    /// let h = Qh::builder().build_from_iter(points.iter().copied()).unwrap().num_vertices();
+    /// assert_eq!(edges.len(), 3 * points.len() - h - 3);
+    /// ```
+    pub fn delaunay_edges(&self) -> Vec<[usize; 2]> {
+        self.delaunay_edge_set().into_iter().collect()
+    }
+
+    /// Edges of the Gabriel graph of the input points
+    ///
+    /// The Gabriel graph keeps the Delaunay edges `[u, v]` whose diametral sphere
+    /// (the sphere with diameter `u v`) contains no other input point:
+    /// `4 * |p - (u + v) / 2|^2 >= |u - v|^2` for every other point `p`.
+    /// Only the Delaunay neighbors of `u` and `v` can violate this condition,
+    /// so they are the only points checked.
+    /// The edges are given like in [`Qh::delaunay_edges`].
+    ///
+    /// # Errors
+    /// * If the hull was not computed in Delaunay mode, see [`Qh::new_delaunay`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [2.0, 0.0],
+    ///     [1.0, 0.2],
+    ///     [1.0, 3.0],
+    /// ]).unwrap();
+    ///
+    /// // the point 2 is inside the circle of diameter 0-1
+    /// assert_eq!(qh.delaunay_edges(), vec![[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+    /// assert_eq!(qh.gabriel_graph().unwrap(), vec![[0, 2], [1, 2], [2, 3]]);
+    /// ```
+    pub fn gabriel_graph(&self) -> Result<Vec<[usize; 2]>, QhError<'static>> {
+        if unsafe { (*self.qh.get()).DELAUNAY == 0 } {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "expected a Delaunay triangulation, see Qh::new_delaunay",
+            ));
+        }
+        // the last coordinate is the lifted one
+        let dim = self.dim - 1;
+        let point = |i: usize| &self.get_input_point(i).expect("vertex is an input point")[..dim];
+        let neighbors = self.delaunay_neighbors();
+        let edges = self.delaunay_edges();
+
+        Ok(edges
+            .into_iter()
+            .filter(|&[u, v]| {
+                let (pu, pv) = (point(u), point(v));
+                let midpoint: Vec<f64> = pu.iter().zip(pv).map(|(a, b)| (a + b) / 2.0).collect();
+                let squared_length: f64 = pu.iter().zip(pv).map(|(a, b)| (a - b).powi(2)).sum();
+                neighbors[u]
+                    .iter()
+                    .chain(&neighbors[v])
+                    .filter(|&&p| p != u && p != v)
+                    .all(|&p| {
+                        let distance: f64 = point(p).iter().zip(&midpoint).map(|(a, b)| (a - b).powi(2)).sum();
+                        4.0 * distance >= squared_length
+                    })
+            })
+            .collect())
+    }
+
+    /// Edges of the lower Delaunay facets, as sorted pairs of input point indices
+    ///
+    /// Every pair of vertices of a simplicial facet is an edge,
+    /// for non-simplicial facets (cospherical points) the edges are taken from the ridges.
+    fn delaunay_edge_set(&self) -> BTreeSet<[usize; 2]> {
+        let mut edges = BTreeSet::new();
+        if unsafe { (*self.qh.get()).DELAUNAY == 0 } {
+            return edges;
+        }
+        let point_index = self.point_index_lookup();
+        let mut add_pairs = |indices: &[usize]| {
+            for (i, &a) in indices.iter().enumerate() {
+                for &b in &indices[i + 1..] {
+                    edges.insert([a.min(b), a.max(b)]);
+                }
+            }
+        };
+
+        for facet in self.facets().filter(|f| !f.upper_delaunay()) {
+            if facet.simplicial() {
+                let indices: Vec<_> = facet
+                    .vertices()
+                    .filter_map(|v| v.point())
+                    .filter_map(point_index)
+                    .collect();
+                add_pairs(&indices);
+            } else {
+                for ridge in facet.ridges().into_iter().flat_map(|s| s.iter()) {
+                    let indices: Vec<_> = ridge
+                        .vertices()
+                        .filter_map(|v| v.point())
+                        .filter_map(point_index)
+                        .collect();
+                    add_pairs(&indices);
+                }
+            }
+        }
+        edges
+    }
+
+    /// Lower Delaunay simplices with `N` vertices, as indices in the input points
+    ///
+    /// `N` is the dimension of the lifted points (one more than the triangulated points).
+    fn delaunay_simplices<const N: usize>(&self) -> Result<Vec<[usize; N]>, QhError<'static>> {
+        let delaunay = unsafe { (*self.qh.get()).DELAUNAY != 0 };
+        if !delaunay || self.hull_dim() != N {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!(
+                    "expected a Delaunay triangulation of {}-d points, see Qh::new_delaunay",
+                    N - 1,
+                ),
+            ));
+        }
+
+        self.faces_with_vertex_indices()
+            .filter(|(f, _)| !f.upper_delaunay())
+            .map(|(f, indices)| {
+                <[usize; N]>::try_from(indices).map_err(|indices| {
+                    QhError::from_message(
+                        QhErrorKind::Input,
+                        format!(
+                            "Delaunay facet f{} has {} vertices instead of {N}, the input has cospherical points",
+                            f.id(),
+                            indices.len(),
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
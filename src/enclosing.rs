@@ -0,0 +1,452 @@
+//! Enclosing and inscribed balls, and extents of the hull
+
+use std::collections::HashSet;
+
+use crate::{helpers, Qh, QhError, QhErrorKind, Vertex};
+
+impl<'a> Qh<'a> {
+    /// The smallest ball containing the hull, as `(center, radius)`
+    ///
+    /// The ball is computed with Welzl's randomized algorithm (in its move-to-front form)
+    /// over the vertices of the hull, since the ball containing them contains the whole hull.
+    /// The vertices are shuffled with a fixed seed, so the result is deterministic.
+    ///
+    /// If the points on the boundary of a candidate ball are affinely dependent
+    /// (e.g. all the vertices are collinear), the ball whose diameter is the farthest pair
+    /// of these points is used instead.
+    /// The radius is `0.0` for a single vertex, the center is empty if there are no vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 2.0],
+    ///         [2.0, 2.0],
+    ///         [1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let (center, radius) = qh.minimum_enclosing_sphere();
+    /// assert!((center[0] - 1.0).abs() < 1e-12 && (center[1] - 1.0).abs() < 1e-12);
+    /// assert!((radius - 2f64.sqrt()).abs() < 1e-12);
+    ///
+    /// // an obtuse triangle: the ball is on its longest side
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [4.0, 0.0], [2.0, 1.0]])
+    ///     .unwrap();
+    /// let (center, radius) = qh.minimum_enclosing_sphere();
+    /// assert!((center[0] - 2.0).abs() < 1e-12 && center[1].abs() < 1e-12);
+    /// assert!((radius - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn minimum_enclosing_sphere(&self) -> (Vec<f64>, f64) {
+        let mut points: Vec<&[f64]> = self.vertices().filter_map(|v| v.point()).collect();
+        if points.is_empty() {
+            return (Vec::new(), 0.0);
+        }
+
+        // Fisher-Yates shuffle with xorshift, for the expected linear time
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for i in (1..points.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            points.swap(i, (state % (i as u64 + 1)) as usize);
+        }
+
+        let n = points.len();
+        let mut support = Vec::with_capacity(self.dim + 1);
+        let (center, radius_squared) = move_to_front_ball(&mut points, n, &mut support, self.dim);
+        (center, radius_squared.sqrt())
+    }
+
+    /// The diameter of the hull and a pair of vertices at this distance
+    ///
+    /// The diameter is the largest distance between two points of the hull,
+    /// which is reached at two vertices.
+    /// All the pairs of vertices are compared, in `O(n^2)` for `n` vertices;
+    /// in 2-d and 3-d the rotating calipers would find the pair faster.
+    /// If several pairs are at the largest distance any one of them is returned.
+    ///
+    /// # Panics
+    /// * If the hull has no vertices (e.g. it has not been computed)
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [3.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [3.0, 1.0],
+    ///         [1.0, 0.5],
+    ///     ]).unwrap();
+    ///
+    /// let (diameter, a, b) = qh.diameter();
+    /// assert!((diameter - 10f64.sqrt()).abs() < 1e-12);
+    /// let (a, b) = (a.point().unwrap(), b.point().unwrap());
+    /// assert!((a[0] - b[0]).abs() == 3.0 && (a[1] - b[1]).abs() == 1.0);
+    /// ```
+    pub fn diameter(&self) -> (f64, Vertex<'_>, Vertex<'_>) {
+        let vertices: Vec<(Vertex, &[f64])> = self
+            .vertices()
+            .filter_map(|v| Some((v, v.point()?)))
+            .collect();
+        assert!(!vertices.is_empty(), "the hull has no vertices");
+
+        let mut farthest = (0.0, 0, 0);
+        for (i, (_, a)) in vertices.iter().enumerate() {
+            for (j, (_, b)) in vertices.iter().enumerate().skip(i + 1) {
+                let d: f64 = a.iter().zip(*b).map(|(x, y)| (x - y).powi(2)).sum();
+                if d > farthest.0 {
+                    farthest = (d, i, j);
+                }
+            }
+        }
+        let (d, i, j) = farthest;
+        (d.sqrt(), vertices[i].0, vertices[j].0)
+    }
+
+    /// The width of the hull in a direction, `max(direction · x) - min(direction · x)`
+    ///
+    /// The extremes are reached at the [extreme points](Qh::extreme_point) in `direction`
+    /// and in the opposite direction. This is also the length of the shadow of the hull
+    /// projected on a line along `direction`.
+    /// The width is scaled by the norm of `direction`, which should be a unit vector
+    /// to get a distance.
+    /// Returns `0.0` if the hull has no vertices.
+    ///
+    /// # Panics
+    /// * If `direction.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [3.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [3.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// assert_eq!(qh.width_in_direction(&[1.0, 0.0]), 3.0);
+    /// assert_eq!(qh.width_in_direction(&[0.0, -1.0]), 1.0);
+    /// let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+    /// assert!((qh.width_in_direction(&[diagonal, diagonal]) - 4.0 * diagonal).abs() < 1e-12);
+    /// ```
+    pub fn width_in_direction(&self, direction: &[f64]) -> f64 {
+        let opposite: Vec<f64> = direction.iter().map(|x| -x).collect();
+        let (Some(max), Some(min)) = (self.extreme_point(direction), self.extreme_point(&opposite)) else {
+            return 0.0;
+        };
+        let projection = |v: Vertex| -> f64 {
+            v.point().map_or(0.0, |p| p.iter().zip(direction).map(|(x, d)| x * d).sum())
+        };
+        projection(max) - projection(min)
+    }
+
+    /// The center and radius of the largest ball inscribed in the hull (its Chebyshev center)
+    ///
+    /// The center `x` and radius `r` maximize `r` with `normal · x + offset <= -r` for every face,
+    /// where the normals are unit vectors (see [`Facet::normal`](crate::Facet::normal)),
+    /// this linear program is solved with the simplex method (with Bland's rule).
+    /// The center is strictly inside the hull, which makes it a good feasible point
+    /// for [`Qh::new_halfspace_intersection`].
+    /// If the ball is not unique (e.g. for a rectangle) any one of the largest balls is returned.
+    ///
+    /// # Errors
+    /// * If the hull has not been computed or has no faces with a hyperplane
+    /// * If the linear program could not be solved, e.g. because the faces do not bound a region
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a right triangle with sides 3, 4 and 5
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]])
+    ///     .unwrap();
+    ///
+    /// let (center, radius) = qh.chebyshev_center().unwrap();
+    /// assert!((radius - 1.0).abs() < 1e-12);
+    /// assert!((center[0] - 1.0).abs() < 1e-12 && (center[1] - 1.0).abs() < 1e-12);
+    ///
+    /// // a cube of side 2
+    /// let qh = Qh::builder()
+    ///     .build_from_iter((0..8).map(|i| [i & 1, i & 2, i & 4].map(|b| if b == 0 { -1.0 } else { 1.0 })))
+    ///     .unwrap();
+    /// let (center, radius) = qh.chebyshev_center().unwrap();
+    /// assert!((radius - 1.0).abs() < 1e-12);
+    /// assert!(center.iter().all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn chebyshev_center(&self) -> Result<(Vec<f64>, f64), QhError<'_>> {
+        if !self.is_computed() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has not been computed yet",
+            ));
+        }
+        let faces: Vec<(&[f64], f64)> = self
+            .facets()
+            .filter_map(|f| Some((f.normal()?, f.offset()?)))
+            .collect();
+        if faces.is_empty() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has no faces with a hyperplane",
+            ));
+        }
+        let dim = self.dim;
+
+        // with `x = origin + y+ - y-` and the origin inside the hull,
+        // the constraints `n · (y+ - y-) + r <= -offset - n · origin` have non-negative bounds,
+        // so that the slack variables are a feasible basis to start from
+        let origin = self.interior_point();
+        let mut a = Vec::with_capacity(faces.len());
+        let mut b = Vec::with_capacity(faces.len());
+        for (normal, offset) in &faces {
+            let mut row: Vec<f64> = normal.to_vec();
+            row.extend(normal.iter().map(|n| -n));
+            row.push(1.0);
+            a.push(row);
+            let distance = -offset - normal.iter().zip(&origin).map(|(n, o)| n * o).sum::<f64>();
+            b.push(distance.max(0.0));
+        }
+        let mut objective = vec![0.0; 2 * dim + 1];
+        objective[2 * dim] = 1.0;
+
+        let Some(solution) = maximize_simplex(a, b, objective) else {
+            return Err(QhError::from_message(
+                QhErrorKind::OtherError,
+                "the linear program of the Chebyshev center could not be solved",
+            ));
+        };
+        let center = (0..dim)
+            .map(|i| origin[i] + solution[i] - solution[dim + i])
+            .collect();
+        Ok((center, solution[2 * dim]))
+    }
+
+    /// A point strictly inside the hull: the average of its vertices
+    ///
+    /// Any convex combination of the vertices with positive weights is strictly inside the hull,
+    /// so the point is always correct and costs `O(n)` for `n` vertices,
+    /// e.g. as the feasible point of a [halfspace intersection](Qh::new_halfspace_intersection).
+    /// Note that this is not the [centroid](Qh::centroid) of the volume,
+    /// and it is not guaranteed to be near the center of the hull:
+    /// it moves towards the regions with many vertices.
+    /// See [`Qh::chebyshev_center`] for the point furthest from the boundary.
+    ///
+    /// Returns an empty vector if the hull has no vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 2.0],
+    ///         [2.0, 2.0],
+    ///         [1.0, 0.5],
+    ///     ]).unwrap();
+    ///
+    /// let point = qh.interior_point();
+    /// assert_eq!(point, vec![1.0, 1.0]);
+    /// assert!(qh.is_point_inside(&point).unwrap());
+    /// ```
+    pub fn interior_point(&self) -> Vec<f64> {
+        let mut sum = vec![0.0; self.dim];
+        let mut count = 0;
+        for point in self.vertices().filter_map(|v| v.point()) {
+            for (s, x) in sum.iter_mut().zip(point) {
+                *s += x;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Vec::new();
+        }
+        sum.iter().map(|s| s / count as f64).collect()
+    }
+
+    /// A point strictly inside the hull, from a few vertices only
+    ///
+    /// The point is the midpoint between the average of the vertices of a face,
+    /// which is in the relative interior of the face, and a vertex of a neighboring face
+    /// that is not on the face, so it is strictly inside the hull.
+    /// This costs `O(dim)` for simplicial faces instead of the `O(n)`
+    /// of [`Qh::interior_point`], but the point is close to the boundary.
+    /// (The average of the first `dim + 1` vertices is not enough:
+    /// they might all be on the same face.)
+    ///
+    /// Returns an empty vector if the hull has no faces.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let point = qh.approximate_interior_point();
+    /// assert!(point.iter().all(|&x| x > 0.0 && x < 1.0));
+    /// assert!(qh.is_point_inside(&point).unwrap());
+    /// ```
+    pub fn approximate_interior_point(&self) -> Vec<f64> {
+        let Some(facet) = self.facets().next() else {
+            return Vec::new();
+        };
+        let on_face: HashSet<u32> = facet.vertices().map(|v| v.id()).collect();
+        let mut face_center = vec![0.0; self.dim];
+        let mut count = 0;
+        for point in facet.vertices().filter_map(|v| v.point()) {
+            for (c, x) in face_center.iter_mut().zip(point) {
+                *c += x;
+            }
+            count += 1;
+        }
+        face_center.iter_mut().for_each(|c| *c /= count as f64);
+
+        let off_face = facet
+            .neighbors()
+            .into_iter()
+            .flat_map(|s| s.iter())
+            .flat_map(|n| n.vertices())
+            .find(|v| !on_face.contains(&v.id()))
+            .and_then(|v| v.point());
+        match off_face {
+            Some(point) => face_center.iter().zip(point).map(|(c, x)| (c + x) / 2.0).collect(),
+            // not a closed hull, fall back to the average of all the vertices
+            None => self.interior_point(),
+        }
+    }
+}
+
+/// Smallest ball containing `points[..n]` with `support` on its boundary,
+/// as `(center, squared radius)`
+///
+/// The points that are found outside of the current ball are moved to the front,
+/// so that later calls check them first.
+fn move_to_front_ball<'p>(
+    points: &mut [&'p [f64]],
+    n: usize,
+    support: &mut Vec<&'p [f64]>,
+    dim: usize,
+) -> (Vec<f64>, f64) {
+    let mut ball = support_ball(support, dim);
+    if support.len() == dim + 1 {
+        return ball;
+    }
+    for i in 0..n {
+        let point = points[i];
+        if !ball_contains(&ball, point) {
+            support.push(point);
+            ball = move_to_front_ball(points, i, support, dim);
+            support.pop();
+            points[..=i].rotate_right(1);
+        }
+    }
+    ball
+}
+
+/// Smallest ball with the `support` points on its boundary
+fn support_ball(support: &[&[f64]], dim: usize) -> (Vec<f64>, f64) {
+    let squared_distance = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>();
+    match support {
+        [] => (vec![0.0; dim], -1.0),
+        [point] => (point.to_vec(), 0.0),
+        _ => {
+            if let Some(center) = helpers::circumcenter(support) {
+                let radius_squared = squared_distance(&center, support[0]);
+                return (center, radius_squared);
+            }
+            // degenerate support, use its farthest pair
+            let mut farthest = (support[0], support[0], 0.0);
+            for (i, a) in support.iter().enumerate() {
+                for b in &support[i + 1..] {
+                    let d = squared_distance(a, b);
+                    if d > farthest.2 {
+                        farthest = (a, b, d);
+                    }
+                }
+            }
+            let (a, b, d) = farthest;
+            (a.iter().zip(b).map(|(x, y)| (x + y) / 2.0).collect(), d / 4.0)
+        }
+    }
+}
+
+/// Check whether `point` is in the ball, with a relative tolerance for rounding errors
+fn ball_contains((center, radius_squared): &(Vec<f64>, f64), point: &[f64]) -> bool {
+    let d: f64 = center.iter().zip(point).map(|(c, x)| (c - x).powi(2)).sum();
+    d <= radius_squared * (1.0 + 1e-10) + f64::EPSILON
+}
+
+/// Maximize `objective · x` subject to `a x <= b` and `x >= 0`, with `b >= 0`
+///
+/// This is the simplex method on a dictionary (the columns of the non-basic variables only),
+/// starting from the basis of the slack variables and using Bland's rule against cycling.
+/// Returns `None` if the program is unbounded or the iterations do not converge.
+fn maximize_simplex(mut a: Vec<Vec<f64>>, mut b: Vec<f64>, mut objective: Vec<f64>) -> Option<Vec<f64>> {
+    const EPS: f64 = 1e-12;
+    let (m, n) = (a.len(), objective.len());
+    // the variables are `0..n`, the slack variables `n..n + m`
+    let mut basic: Vec<usize> = (n..n + m).collect();
+    let mut non_basic: Vec<usize> = (0..n).collect();
+
+    for _ in 0..100 * (n + m) {
+        let entering = (0..n)
+            .filter(|&j| objective[j] > EPS)
+            .min_by_key(|&j| non_basic[j]);
+        let Some(j) = entering else {
+            let mut x = vec![0.0; n];
+            for (&var, &value) in basic.iter().zip(&b) {
+                if var < n {
+                    x[var] = value;
+                }
+            }
+            return Some(x);
+        };
+        let leaving = (0..m)
+            .filter(|&i| a[i][j] > EPS)
+            .min_by(|&i, &k| {
+                (b[i] / a[i][j])
+                    .total_cmp(&(b[k] / a[k][j]))
+                    .then(basic[i].cmp(&basic[k]))
+            });
+        let r = leaving?;
+
+        // exchange the basic variable of row `r` with the non-basic variable of column `j`
+        let pivot = a[r][j];
+        let pivot_row: Vec<f64> = a[r].iter().map(|x| x / pivot).collect();
+        let pivot_b = b[r] / pivot;
+        for i in 0..m {
+            if i == r {
+                continue;
+            }
+            let factor = a[i][j];
+            if factor != 0.0 {
+                for (x, p) in a[i].iter_mut().zip(&pivot_row) {
+                    *x -= factor * p;
+                }
+                a[i][j] = -factor / pivot;
+                b[i] = (b[i] - factor * pivot_b).max(0.0);
+            }
+        }
+        a[r] = pivot_row;
+        a[r][j] = 1.0 / pivot;
+        b[r] = pivot_b;
+        let factor = objective[j];
+        for (c, p) in objective.iter_mut().zip(&a[r]) {
+            *c -= factor * p;
+        }
+        objective[j] = -factor / pivot;
+        std::mem::swap(&mut basic[r], &mut non_basic[j]);
+    }
+    None
+}
@@ -6,10 +6,14 @@ macro_rules! define_error_kinds {
     (
         $(
             $(#[$attr:meta])*
-            $name:ident => $code:literal,
+            $name:ident => $code:literal
         ),*$(,)?
     ) => {
+        /// Kind of a [`QhError`]
+        ///
+        /// The variants correspond to the `qh_ERR...` exit codes defined in `libqhull_r.h`.
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum QhErrorKind {
             $(
                 $(#[$attr])*
@@ -23,6 +27,18 @@ macro_rules! define_error_kinds {
         }
 
         impl QhErrorKind {
+            /// Get the error kind from a Qhull error code
+            ///
+            /// # Panics
+            /// * If `code` is `0` (`qh_ERRnone`), since it is not an error
+            ///
+            /// # Example
+            /// ```
+            /// # use qhull::*;
+            /// assert_eq!(QhErrorKind::from_code(1), QhErrorKind::Input);
+            /// assert_eq!(QhErrorKind::from_code(42), QhErrorKind::Other(42));
+            /// assert_eq!(QhErrorKind::Precision.error_code(), 3);
+            /// ```
             pub fn from_code(code: i32) -> Self {
                 match code {
                     0 => panic!("0 is not an error code"),
@@ -32,6 +48,7 @@ macro_rules! define_error_kinds {
                     _ => Self::Other(code),
                 }
             }
+            /// Get the Qhull error code
             pub fn error_code(&self) -> i32 {
                 match self {
                     $(
@@ -45,7 +62,42 @@ macro_rules! define_error_kinds {
 }
 
 define_error_kinds! {
-    // TODO ...
+    /// Input inconsistency
+    ///
+    /// Corresponds to `qh_ERRinput`.
+    Input => 1,
+    /// Singular input data
+    ///
+    /// Corresponds to `qh_ERRsingular`.
+    Singular => 2,
+    /// Precision error
+    ///
+    /// Corresponds to `qh_ERRprec`.
+    Precision => 3,
+    /// Insufficient memory
+    ///
+    /// Corresponds to `qh_ERRmem`.
+    Memory => 4,
+    /// Internal error detected by Qhull
+    ///
+    /// Corresponds to `qh_ERRqhull`.
+    Qhull => 5,
+    /// Other error detected by Qhull
+    ///
+    /// Corresponds to `qh_ERRother`.
+    OtherError => 6,
+    /// Topology error, maybe due to nearly adjacent vertices
+    ///
+    /// Corresponds to `qh_ERRtopology`.
+    Topology => 7,
+    /// Wide facet error, maybe due to nearly adjacent vertices
+    ///
+    /// Corresponds to `qh_ERRwide`.
+    Wide => 8,
+    /// Error raised from Qhull debugging code
+    ///
+    /// Corresponds to `qh_ERRdebug`.
+    Debug => 9,
 }
 
 /// A Qhull error
@@ -85,13 +137,122 @@ impl<'a> Display for QhError<'a> {
     }
 }
 
-impl<'a> Error for QhError<'a> {}
+impl<'a> Error for QhError<'a> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // Qhull errors do not wrap other errors (for now)
+        None
+    }
+}
+
+/// Serialized form of a [`QhError`], without the references to the problematic elements
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "QhError")]
+struct QhErrorRepr {
+    kind: QhErrorKind,
+    error_message: Option<String>,
+}
+
+/// Only the [`kind`](QhError::kind) and the [`error_message`](QhError::error_message) are serialized.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// # use qhull::*;
+/// let Err(error) = Qh::builder().build_from_iter((0..10).map(|i| [0.0, i as f64])) else {
+///     panic!("aligned points should not have a convex hull");
+/// };
+/// let json = serde_json::to_string(&error).unwrap();
+/// let deserialized: QhError = serde_json::from_str(&json).unwrap();
+/// assert_eq!(deserialized.kind, error.kind);
+/// assert_eq!(deserialized.error_message, error.error_message);
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for QhError<'static> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QhErrorRepr {
+            kind: self.kind,
+            error_message: self.error_message.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for QhError<'static> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let QhErrorRepr { kind, error_message } = QhErrorRepr::deserialize(deserializer)?;
+        Ok(QhError {
+            kind,
+            error_message,
+            face: None,
+            ridge: None,
+            vertex: None,
+        })
+    }
+}
+
+/// Result type for operations that can fail with a [`QhError`]
+///
+/// The error may borrow from the [`Qh`](crate::Qh) instance it originates from.
+pub type QhResult<'a, T> = Result<T, QhError<'a>>;
+
+/// Result type for operations that can fail with a `'static` [`QhError`]
+///
+/// Since `QhError<'static>` implements [`Error`], it converts to [`Box<dyn Error>`]
+/// so `?` can be used in functions returning `Result<_, Box<dyn Error>>`.
+/// Errors borrowing from a [`Qh`](crate::Qh) instance must be converted with [`QhError::into_static`] first.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// use std::error::Error;
+///
+/// fn num_facets() -> Result<usize, Box<dyn Error>> {
+///     let mut qh = Qh::builder()
+///         .compute(false)
+///         .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])?;
+///     qh.compute().map_err(QhError::into_static)?;
+///     Ok(qh.num_facets())
+/// }
+///
+/// assert_eq!(num_facets().unwrap(), 3);
+/// ```
+pub type QhResultStatic<T> = Result<T, QhError<'static>>;
+
+impl QhError<'static> {
+    /// Create an error that does not originate from Qhull
+    ///
+    /// Used for errors detected on the Rust side, for example
+    /// when a method is called on an instance that is not in the right state.
+    pub(crate) fn from_message(kind: QhErrorKind, message: impl Into<String>) -> Self {
+        QhError {
+            kind,
+            error_message: Some(message.into()),
+            face: None,
+            ridge: None,
+            vertex: None,
+        }
+    }
+}
+
+/// I/O errors, e.g. while writing a file, are reported as [`QhErrorKind::OtherError`]
+impl From<std::io::Error> for QhError<'static> {
+    fn from(error: std::io::Error) -> Self {
+        QhError::from_message(QhErrorKind::OtherError, format!("I/O error: {error}"))
+    }
+}
 
 impl<'a> QhError<'a> {
     /// Convert the error to a `'static` error.
     ///
     /// This is useful when you want to return the error from a function that is not tied to a [`Qh`](crate::Qh) reference.
     ///
+    /// The references to the problematic elements ([`face`](QhError::face), [`ridge`](QhError::ridge)
+    /// and [`vertex`](QhError::vertex)) are discarded, only the kind and the message are kept.
+    /// With the `log` feature enabled, the discarded elements are reported with [`log::warn!`](https://docs.rs/log/latest/log/macro.warn.html).
+    ///
     /// # Example
     /// ```
     /// # use qhull::*;
@@ -126,24 +287,20 @@ impl<'a> QhError<'a> {
             ridge,
             vertex,
         } = self;
-        if let Some(face) = face {
-            eprintln!(
-                "During conversion to static, a face was discarded: {:?}",
-                face
-            );
-        }
-        if let Some(ridge) = ridge {
-            eprintln!(
-                "During conversion to static, a ridge was discarded: {:?}",
-                ridge
-            );
-        }
-        if let Some(vertex) = vertex {
-            eprintln!(
-                "During conversion to static, a vertex was discarded: {:?}",
-                vertex
-            );
+        #[cfg(feature = "log")]
+        {
+            if let Some(face) = face {
+                log::warn!("During conversion to static, a face was discarded: {:?}", face);
+            }
+            if let Some(ridge) = ridge {
+                log::warn!("During conversion to static, a ridge was discarded: {:?}", ridge);
+            }
+            if let Some(vertex) = vertex {
+                log::warn!("During conversion to static, a vertex was discarded: {:?}", vertex);
+            }
         }
+        #[cfg(not(feature = "log"))]
+        let _ = (face, ridge, vertex);
         QhError {
             kind,
             error_message,
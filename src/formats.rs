@@ -0,0 +1,535 @@
+//! Import and export of the hull in common file formats
+
+use std::io::{BufRead, BufReader, Read, Write};
+
+use crate::{sys, tmp_file::TmpFile, Qh, QhBuilder, QhError, QhErrorKind};
+
+impl<'a> Qh<'a> {
+    /// Write the hull in the Object File Format (OFF)
+    ///
+    /// 3-d hulls are written as `OFF`: the header, the numbers of vertices, faces and edges,
+    /// the coordinates of the vertices and the faces as lists of vertex indices
+    /// (counter-clockwise seen from outside).
+    /// Other dimensions are written as `nOFF`, with the dimension after the header
+    /// and the number of edges set to `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use std::io::Cursor;
+    ///
+    /// // a tetrahedron
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// qh.write_off(&mut cursor).unwrap();
+    /// let off = String::from_utf8(cursor.into_inner()).unwrap();
+    ///
+    /// let mut lines = off.lines();
+    /// assert_eq!(lines.next(), Some("OFF"));
+    /// assert_eq!(lines.next(), Some("4 4 6"));
+    /// assert_eq!(off.lines().filter(|l| l.starts_with("3 ")).count(), 4);
+    /// ```
+    pub fn write_off<W: Write>(&self, writer: &mut W) -> Result<(), QhError<'static>> {
+        let (vertices, faces) = self.indexed_faces();
+        if self.dim == 3 {
+            let edges = faces.iter().map(|f| f.len()).sum::<usize>() / 2;
+            writeln!(writer, "OFF")?;
+            writeln!(writer, "{} {} {}", vertices.len(), faces.len(), edges)?;
+        } else {
+            writeln!(writer, "nOFF")?;
+            writeln!(writer, "{}", self.dim)?;
+            writeln!(writer, "{} {} 0", vertices.len(), faces.len())?;
+        }
+        for vertex in &vertices {
+            writeln!(writer, "{}", join(vertex.iter()))?;
+        }
+        for face in &faces {
+            writeln!(writer, "{} {}", face.len(), join(face.iter()))?;
+        }
+        Ok(())
+    }
+
+    /// Write a 3-d hull in the Wavefront OBJ format
+    ///
+    /// The vertices are written as `v x y z` lines, the face normals as `vn nx ny nz` lines
+    /// and the faces as `f v//vn ...` lines.
+    /// Faces that are not triangles are written as polygons
+    /// with their vertices in counter-clockwise order seen from outside.
+    ///
+    /// # Errors
+    /// * If the hull is not 3-d
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a square pyramid
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.5, 0.5, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut obj = Vec::new();
+    /// qh.write_obj(&mut obj).unwrap();
+    /// let obj = String::from_utf8(obj).unwrap();
+    /// assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 5);
+    /// assert_eq!(obj.lines().filter(|l| l.starts_with("vn ")).count(), 5);
+    /// assert!(obj.lines().any(|l| l.starts_with("f ") && l.split_whitespace().count() == 5));
+    ///
+    /// // read the vertices back
+    /// let qh = QhBuilder::default().build_from_obj(&mut obj.as_bytes()).unwrap();
+    /// assert_eq!(qh.num_vertices(), 5);
+    /// ```
+    pub fn write_obj<W: Write>(&self, writer: &mut W) -> Result<(), QhError<'static>> {
+        if self.dim != 3 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("OBJ files require a 3-d hull, got dimension {}", self.dim),
+            ));
+        }
+
+        let (vertices, faces) = self.indexed_faces();
+        for vertex in &vertices {
+            writeln!(writer, "v {}", join(vertex.iter()))?;
+        }
+        let normals = self
+            .facets()
+            .filter(|f| f.ordered_vertices_3d().is_some())
+            .filter_map(|f| f.normal());
+        for normal in normals {
+            writeln!(writer, "vn {}", join(normal.iter()))?;
+        }
+        for (i, face) in faces.iter().enumerate() {
+            // OBJ indices start at 1
+            let face = face.iter().map(|v| format!("{}//{}", v + 1, i + 1));
+            writeln!(writer, "f {}", join(face))?;
+        }
+        Ok(())
+    }
+
+    /// Write a 3-d hull in the Polygon File Format (PLY)
+    ///
+    /// The file has a `vertex` element with the `x y z` properties
+    /// and a `face` element with the `vertex_indices` list property,
+    /// the faces are polygons with their vertices in counter-clockwise order seen from outside.
+    ///
+    /// With `binary`, the body is written as `binary_little_endian`:
+    /// `float` (32 bits) coordinates, and `uchar` counts followed by `uint` (32 bits) indices.
+    /// Otherwise it is written as `ascii`.
+    ///
+    /// # Errors
+    /// * If the hull is not 3-d
+    /// * If a face has more than 255 vertices (the count is declared as `uchar`)
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a tetrahedron
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut ply = Vec::new();
+    /// qh.write_ply(&mut ply, false).unwrap();
+    /// let ply = String::from_utf8(ply).unwrap();
+    /// assert!(ply.starts_with("ply\nformat ascii 1.0\n"));
+    /// assert!(ply.contains("element vertex 4\n"));
+    /// assert!(ply.contains("element face 4\n"));
+    ///
+    /// let mut binary = Vec::new();
+    /// qh.write_ply(&mut binary, true).unwrap();
+    /// let header_end = b"end_header\n";
+    /// let body = binary
+    ///     .windows(header_end.len())
+    ///     .position(|w| w == header_end)
+    ///     .unwrap() + header_end.len();
+    /// // 4 vertices * 3 floats, 4 faces * (1 count + 3 indices)
+    /// assert_eq!(binary.len() - body, 4 * 3 * 4 + 4 * (1 + 3 * 4));
+    /// ```
+    pub fn write_ply<W: Write>(&self, writer: &mut W, binary: bool) -> Result<(), QhError<'static>> {
+        if self.dim != 3 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("PLY files require a 3-d hull, got dimension {}", self.dim),
+            ));
+        }
+
+        let (vertices, faces) = self.indexed_faces();
+        if let Some(face) = faces.iter().find(|f| f.len() > u8::MAX as usize) {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("PLY faces have at most 255 vertices, got {}", face.len()),
+            ));
+        }
+        let format = if binary { "binary_little_endian" } else { "ascii" };
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format {format} 1.0")?;
+        writeln!(writer, "element vertex {}", vertices.len())?;
+        writeln!(writer, "property float x")?;
+        writeln!(writer, "property float y")?;
+        writeln!(writer, "property float z")?;
+        writeln!(writer, "element face {}", faces.len())?;
+        writeln!(writer, "property list uchar uint vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        if binary {
+            for vertex in &vertices {
+                for &x in vertex.iter() {
+                    writer.write_all(&(x as f32).to_le_bytes())?;
+                }
+            }
+            for face in &faces {
+                writer.write_all(&[face.len() as u8])?;
+                for &index in face {
+                    writer.write_all(&(index as u32).to_le_bytes())?;
+                }
+            }
+        } else {
+            for vertex in &vertices {
+                writeln!(writer, "{}", join(vertex.iter()))?;
+            }
+            for face in &faces {
+                writeln!(writer, "{} {}", face.len(), join(face.iter()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw a 2-d hull as an SVG image
+    ///
+    /// The hull is drawn as a `<polygon>` with its vertices in counter-clockwise order,
+    /// and, with [`SvgOptions::show_points`], the input points are drawn as `<circle>` elements.
+    /// The coordinates are scaled to fit the image, with the `y` axis pointing up.
+    ///
+    /// # Errors
+    /// * If the hull is not 2-d
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut svg = Vec::new();
+    /// qh.write_svg(&mut svg, SvgOptions {
+    ///     fill_color: "lightblue".to_string(),
+    ///     ..Default::default()
+    /// }).unwrap();
+    /// let svg = String::from_utf8(svg).unwrap();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<polygon").count(), 1);
+    /// assert_eq!(svg.matches("<circle").count(), 4);
+    /// ```
+    pub fn write_svg<W: Write>(&self, writer: &mut W, opts: SvgOptions) -> Result<(), QhError<'static>> {
+        if self.dim != 2 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("SVG export requires a 2-d hull, got dimension {}", self.dim),
+            ));
+        }
+
+        let points: &[f64] = unsafe {
+            let first = sys::qh_get_first_point(self.qh.get());
+            let num_points = sys::qh_get_num_points(self.qh.get()) as usize;
+            if first.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(first, num_points * 2)
+            }
+        };
+
+        // hull vertices sorted by angle around their center
+        let mut hull = self.vertices().filter_map(|v| v.point()).collect::<Vec<_>>();
+        let n = hull.len().max(1) as f64;
+        let cx = hull.iter().map(|p| p[0]).sum::<f64>() / n;
+        let cy = hull.iter().map(|p| p[1]).sum::<f64>() / n;
+        let angle = |p: &[f64]| (p[1] - cy).atan2(p[0] - cx);
+        hull.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+
+        // fit the bounding box in the image, keeping the aspect ratio
+        let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+        for p in points.chunks(2).chain(hull.iter().copied()) {
+            for i in 0..2 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        let margin = 0.05 * opts.width.min(opts.height);
+        let extent = (max[0] - min[0]).max(max[1] - min[1]);
+        let scale = if extent > 0.0 {
+            (opts.width - 2.0 * margin).min(opts.height - 2.0 * margin) / extent
+        } else {
+            1.0
+        };
+        let x = |p: &[f64]| margin + (p[0] - min[0]) * scale;
+        let y = |p: &[f64]| opts.height - margin - (p[1] - min[1]) * scale;
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = opts.width,
+            h = opts.height,
+        )?;
+        let polygon = hull
+            .iter()
+            .map(|p| format!("{},{}", x(p), y(p)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(
+            writer,
+            r#"  <polygon points="{polygon}" stroke="{}" fill="{}"/>"#,
+            opts.stroke_color, opts.fill_color,
+        )?;
+        if opts.show_points {
+            let radius = 0.01 * opts.width.min(opts.height);
+            for p in points.chunks(2) {
+                writeln!(
+                    writer,
+                    r#"  <circle cx="{}" cy="{}" r="{radius}" fill="{}"/>"#,
+                    x(p),
+                    y(p),
+                    opts.stroke_color,
+                )?;
+            }
+        }
+        writeln!(writer, "</svg>")?;
+        Ok(())
+    }
+
+    /// Write qhull's text summary of the hull
+    ///
+    /// This is the summary printed by the `qhull` command-line tool (option `s`),
+    /// written by [`qh_printsummary`](sys::qh_printsummary): the number of points,
+    /// vertices and facets, the options and a few statistics of the computation.
+    ///
+    /// # Errors
+    /// * If the temporary file for qhull's output cannot be created or read
+    /// * If qhull fails to print the summary
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut summary = Vec::new();
+    /// qh.write_summary(&mut summary).unwrap();
+    /// let summary = String::from_utf8(summary).unwrap();
+    /// assert!(summary.contains("Convex hull of 4 points in 2-d"));
+    /// assert!(summary.contains("Number of vertices: 3"));
+    /// ```
+    pub fn write_summary<W: Write>(&self, writer: &mut W) -> Result<(), QhError<'static>> {
+        let file = TmpFile::new()?;
+        unsafe {
+            Qh::try_on_qh(self, |qh| sys::qh_printsummary(qh as *mut _, file.file_handle()))
+                .map_err(|e| e.into_static())?;
+        }
+        writer.write_all(&file.read_and_close()?)?;
+        Ok(())
+    }
+}
+
+/// Options for [`Qh::write_svg`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// Width of the image
+    pub width: f64,
+    /// Height of the image
+    pub height: f64,
+    /// Color of the hull boundary and of the points
+    pub stroke_color: String,
+    /// Color of the inside of the hull, `"none"` for no fill
+    pub fill_color: String,
+    /// Whether to draw the input points
+    pub show_points: bool,
+}
+
+/// Default options:
+/// * `400 x 400` image
+/// * black stroke, no fill
+/// * points are shown
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            width: 400.0,
+            height: 400.0,
+            stroke_color: "black".to_string(),
+            fill_color: "none".to_string(),
+            show_points: true,
+        }
+    }
+}
+
+impl QhBuilder {
+    /// Build a Qhull instance from the vertices of a Wavefront OBJ file
+    ///
+    /// Only the `v x y z` lines are read (an optional `w` coordinate is ignored),
+    /// all the other lines (faces, normals, comments, ...) are skipped.
+    ///
+    /// See [`Qh::write_obj`] for an example.
+    ///
+    /// # Errors
+    /// * If the file cannot be read, or a `v` line does not have 3 valid coordinates
+    pub fn build_from_obj<R: Read>(self, reader: &mut R) -> Result<Qh<'static>, QhError<'static>> {
+        let mut coords = Vec::new();
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let mut tokens = line.split_whitespace();
+            if tokens.next() != Some("v") {
+                continue;
+            }
+            let point = tokens
+                .take(3)
+                .map(|t| t.parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .ok()
+                .filter(|p| p.len() == 3)
+                .ok_or_else(|| {
+                    QhError::from_message(
+                        QhErrorKind::Input,
+                        format!("invalid vertex at line {} of the OBJ file: {line:?}", i + 1),
+                    )
+                })?;
+            coords.extend(point);
+        }
+        self.build_managed(3, coords)
+    }
+
+    /// Build a Qhull instance from the vertices of an Object File Format (OFF) file
+    ///
+    /// Supported headers are `OFF` and `NOFF` (3-d), `4OFF` (4-d)
+    /// and `nOFF` (the dimension follows the header), as written by [`Qh::write_off`].
+    /// Only the vertices are read: normals (`N`) and the faces are ignored.
+    /// Colors and texture coordinates (`C`, `ST`) and homogeneous coordinates with `nOFF` are not supported.
+    ///
+    /// # Errors
+    /// * If the file cannot be read, or is not a valid or supported OFF file
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let off = "\
+    /// OFF
+    /// 5 0 0 # a square pyramid, the faces are not needed
+    /// 0 0 0
+    /// 1 0 0
+    /// 0 1 0
+    /// 1 1 0
+    /// 0.5 0.5 1
+    /// ";
+    /// let qh = QhBuilder::default().build_from_off(&mut off.as_bytes()).unwrap();
+    /// assert_eq!(qh.num_facets(), 5);
+    ///
+    /// // round trip
+    /// let mut written = Vec::new();
+    /// qh.write_off(&mut written).unwrap();
+    /// let qh = QhBuilder::default().build_from_off(&mut written.as_slice()).unwrap();
+    /// assert_eq!(qh.num_facets(), 5);
+    ///
+    /// assert!(QhBuilder::default().build_from_off(&mut "COFF\n0 0 0\n".as_bytes()).is_err());
+    /// ```
+    pub fn build_from_off<R: Read>(self, reader: &mut R) -> Result<Qh<'static>, QhError<'static>> {
+        let invalid = |message: String| QhError::from_message(QhErrorKind::Input, message);
+
+        // non-empty lines without comments
+        let mut lines = Vec::new();
+        for line in BufReader::new(reader).lines() {
+            let line = line?;
+            let line = line.split('#').next().unwrap_or_default();
+            let tokens = line.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+            if !tokens.is_empty() {
+                lines.push(tokens);
+            }
+        }
+        let mut lines = lines.into_iter();
+
+        let mut header = lines.next().unwrap_or_default().into_iter();
+        let keyword = header.next().ok_or_else(|| invalid("empty OFF file".to_string()))?;
+        let prefix = keyword
+            .strip_suffix("OFF")
+            .ok_or_else(|| invalid(format!("invalid OFF header {keyword:?}")))?;
+        if prefix.contains(['C', 'S', 'T']) || (prefix.contains('4') && prefix.contains('n')) {
+            return Err(invalid(format!("unsupported OFF variant {keyword:?}")));
+        }
+        if let Some(c) = prefix.chars().find(|c| !matches!(c, 'N' | '4' | 'n')) {
+            return Err(invalid(format!("invalid OFF header {keyword:?}: unknown prefix {c:?}")));
+        }
+
+        // the numbers after the header can be on the same or the following lines
+        let mut header = header.collect::<Vec<_>>();
+        let needed = if prefix.contains('n') { 4 } else { 3 };
+        while header.len() < needed {
+            let line = lines
+                .next()
+                .ok_or_else(|| invalid("unexpected end of the OFF header".to_string()))?;
+            header.extend(line);
+        }
+        let numbers = header[..needed]
+            .iter()
+            .map(|token| {
+                token
+                    .parse::<usize>()
+                    .map_err(|_| invalid(format!("invalid number {token:?} in the OFF header")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let (dim, num_vertices) = if prefix.contains('n') {
+            (numbers[0], numbers[1])
+        } else if prefix.contains('4') {
+            (4, numbers[0])
+        } else {
+            (3, numbers[0])
+        };
+
+        let mut coords = Vec::with_capacity(num_vertices * dim);
+        for i in 0..num_vertices {
+            let line = lines
+                .next()
+                .ok_or_else(|| invalid(format!("expected {num_vertices} vertices, got {i}")))?;
+            if line.len() < dim {
+                return Err(invalid(format!("vertex {i} has less than {dim} coordinates")));
+            }
+            for token in &line[..dim] {
+                let x = token
+                    .parse::<f64>()
+                    .map_err(|_| invalid(format!("invalid coordinate {token:?} of vertex {i}")))?;
+                coords.push(x);
+            }
+        }
+
+        self.build_from_flat_slice(dim, coords)
+    }
+}
+
+/// Space separated values
+fn join<T: std::fmt::Display>(values: impl Iterator<Item = T>) -> String {
+    values.map(|v| v.to_string()).collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,76 @@
+//! Integration with the [`glam`] crate
+
+use glam::Vec3;
+
+use crate::{Facet, Qh, QhBuilder, QhError, Vertex};
+
+impl QhBuilder {
+    /// Build a 3-d Qhull instance from [`glam`] vectors
+    ///
+    /// Accepts both [`Vec3`] and [`glam::Vec3A`] points,
+    /// the coordinates are converted to `f64`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use glam::{Vec3, Vec3A};
+    ///
+    /// let qh = QhBuilder::default()
+    ///     .build_from_vec3_iter([
+    ///         Vec3::new(0.0, 0.0, 0.0),
+    ///         Vec3::new(1.0, 0.0, 0.0),
+    ///         Vec3::new(0.0, 1.0, 0.0),
+    ///         Vec3::new(0.0, 0.0, 1.0),
+    ///         Vec3::new(0.1, 0.1, 0.1),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    ///
+    /// for vertex in qh.vertices() {
+    ///     assert!(vertex.as_vec3().unwrap().element_sum() <= 1.0);
+    /// }
+    /// for facet in qh.facets() {
+    ///     assert!(facet.normal_as_vec3().unwrap().is_normalized());
+    /// }
+    ///
+    /// let qh = QhBuilder::default()
+    ///     .build_from_vec3_iter([Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// ```
+    pub fn build_from_vec3_iter(
+        self,
+        points: impl IntoIterator<Item = impl Into<Vec3>>,
+    ) -> Result<Qh<'static>, QhError<'static>> {
+        let coords = points
+            .into_iter()
+            .flat_map(|p| p.into().to_array())
+            .map(f64::from)
+            .collect();
+        self.build_from_flat_slice(3, coords)
+    }
+}
+
+impl<'a> Facet<'a> {
+    /// Normal of a 3-d facet as a [`Vec3`]
+    ///
+    /// Returns `None` if the facet has no normal (see [`Facet::normal`])
+    /// or if the hull is not 3-d.
+    pub fn normal_as_vec3(&self) -> Option<Vec3> {
+        self.normal()
+            .filter(|n| n.len() == 3)
+            .map(|n| Vec3::new(n[0] as f32, n[1] as f32, n[2] as f32))
+    }
+}
+
+impl<'a> Vertex<'a> {
+    /// Coordinates of a 3-d vertex as a [`Vec3`]
+    ///
+    /// Returns `None` if the vertex has no coordinates (see [`Vertex::coordinates`])
+    /// or if the hull is not 3-d.
+    pub fn as_vec3(&self) -> Option<Vec3> {
+        self.coordinates()
+            .filter(|p| p.len() == 3)
+            .map(|p| Vec3::new(p[0] as f32, p[1] as f32, p[2] as f32))
+    }
+}
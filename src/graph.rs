@@ -0,0 +1,407 @@
+//! Adjacency of the faces of the hull
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{Facet, Qh};
+
+/// The adjacency graph of the faces of a hull, see [`Qh::face_graph`]
+///
+/// The nodes are the positions of the faces in [`Qh::facets`] (`0..num_faces`),
+/// two faces are adjacent if they share a ridge.
+/// The graph is an owned copy: it does not borrow the hull.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FaceGraph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl FaceGraph {
+    /// Create a graph from an adjacency list
+    ///
+    /// Entry `i` contains the nodes adjacent to node `i`,
+    /// the list is expected to be symmetric.
+    pub fn new(adjacency: Vec<Vec<usize>>) -> Self {
+        Self { adjacency }
+    }
+
+    /// Number of faces (nodes) of the graph
+    pub fn num_faces(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The faces adjacent to `face`
+    ///
+    /// # Panics
+    /// * If `face` is not smaller than [`FaceGraph::num_faces`]
+    pub fn neighbors(&self, face: usize) -> &[usize] {
+        &self.adjacency[face]
+    }
+
+    /// The adjacency list of the graph
+    pub fn adjacency_list(&self) -> &[Vec<usize>] {
+        &self.adjacency
+    }
+
+    /// Get back the adjacency list of the graph
+    pub fn into_adjacency_list(self) -> Vec<Vec<usize>> {
+        self.adjacency
+    }
+
+    /// Iterate over the faces reachable from `start` in breadth-first order
+    ///
+    /// `start` is the first face, each reachable face is yielded once.
+    ///
+    /// # Panics
+    /// * If `start` is not smaller than [`FaceGraph::num_faces`]
+    pub fn bfs(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut visited = vec![false; self.num_faces()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        std::iter::from_fn(move || {
+            let face = queue.pop_front()?;
+            for &neighbor in &self.adjacency[face] {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+            Some(face)
+        })
+    }
+
+    /// A shortest path from `from` to `to`, both included
+    ///
+    /// Returns `None` if `to` is not reachable from `from`.
+    ///
+    /// # Panics
+    /// * If `from` or `to` is not smaller than [`FaceGraph::num_faces`]
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        assert!(to < self.num_faces(), "face {to} is not in the graph");
+        let mut previous = vec![None; self.num_faces()];
+        previous[from] = Some(from);
+        let mut queue = VecDeque::from([from]);
+        while let Some(face) = queue.pop_front() {
+            if face == to {
+                break;
+            }
+            for &neighbor in &self.adjacency[face] {
+                if previous[neighbor].is_none() {
+                    previous[neighbor] = Some(face);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        previous[to]?;
+        let mut path = vec![to];
+        while let Some(&face) = path.last().filter(|&&f| f != from) {
+            path.push(previous[face].unwrap());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The connected components of the graph
+    ///
+    /// Each component lists its faces in breadth-first order,
+    /// the components are sorted by their first face.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut component = vec![None; self.num_faces()];
+        let mut components = Vec::new();
+        for start in 0..self.num_faces() {
+            if component[start].is_some() {
+                continue;
+            }
+            let faces: Vec<usize> = self.bfs(start).collect();
+            for &face in &faces {
+                component[face] = Some(components.len());
+            }
+            components.push(faces);
+        }
+        components
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// Adjacency list of the faces of the hull
+    ///
+    /// Faces are numbered by their position in [`Qh::facets`],
+    /// entry `i` contains the faces adjacent to face `i`.
+    ///
+    /// The adjacency is read from the [neighbors](Facet::neighbors) of each face,
+    /// in `O(n + m)` for `n` faces and `m` adjacencies:
+    /// qhull does not store the ridges between simplicial faces (see [`Qh::all_ridges`]),
+    /// but it always stores the neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a tetrahedron: every face is adjacent to the 3 others
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let adjacency = qh.face_adjacency_list();
+    /// assert_eq!(adjacency.len(), 4);
+    /// for (i, neighbors) in adjacency.iter().enumerate() {
+    ///     assert_eq!(neighbors.len(), 3);
+    ///     assert!(!neighbors.contains(&i));
+    /// }
+    /// ```
+    pub fn face_adjacency_list(&self) -> Vec<Vec<usize>> {
+        let index: HashMap<u32, usize> = self
+            .facets()
+            .enumerate()
+            .map(|(i, f)| (f.id(), i))
+            .collect();
+        self.facets()
+            .map(|facet| {
+                facet
+                    .neighbors()
+                    .into_iter()
+                    .flat_map(|s| s.iter())
+                    .filter_map(|n| index.get(&n.id()).copied())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The adjacency graph of the faces of the hull
+    ///
+    /// See [`Qh::face_adjacency_list`] and [`FaceGraph`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let graph = qh.face_graph();
+    /// assert_eq!(graph.num_faces(), 6);
+    /// assert_eq!(graph.bfs(0).count(), 6);
+    /// assert_eq!(graph.connected_components().len(), 1);
+    ///
+    /// // the opposite face is reached through one of the 4 side faces
+    /// let facets: Vec<_> = qh.facets().collect();
+    /// let normal = facets[0].normal().unwrap();
+    /// let opposite = facets
+    ///     .iter()
+    ///     .position(|f| f.normal().unwrap().iter().zip(normal).all(|(a, b)| (a + b).abs() < 1e-12))
+    ///     .unwrap();
+    /// assert_eq!(graph.shortest_path(0, opposite).unwrap().len(), 3);
+    /// assert_eq!(graph.shortest_path(0, 0), Some(vec![0]));
+    /// ```
+    pub fn face_graph(&self) -> FaceGraph {
+        FaceGraph::new(self.face_adjacency_list())
+    }
+
+    /// The dual graph of the hull, by face id
+    ///
+    /// The keys are the [ids](Facet::id) of the faces, the values the ids of their neighbors.
+    /// This is [`Qh::face_adjacency_list`] with qhull's ids instead of positions,
+    /// which is convenient when the ids are used to refer to data stored elsewhere.
+    ///
+    /// The dual graph of a 3-d hull is always planar.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// let graph = qh.dual_graph();
+    /// assert_eq!(graph.len(), 3);
+    /// for (id, neighbors) in &graph {
+    ///     assert_eq!(neighbors.len(), 2);
+    ///     for neighbor in neighbors {
+    ///         assert!(graph[neighbor].contains(id));
+    ///     }
+    /// }
+    /// ```
+    pub fn dual_graph(&self) -> HashMap<u32, Vec<u32>> {
+        self.facets()
+            .map(|facet| {
+                let neighbors = facet
+                    .neighbors()
+                    .into_iter()
+                    .flat_map(|s| s.iter())
+                    .map(|n| n.id())
+                    .collect();
+                (facet.id(), neighbors)
+            })
+            .collect()
+    }
+
+    /// Map from the vertices to the faces containing them, by id
+    ///
+    /// The keys are the [ids](crate::Vertex::id) of the vertices, the values the ids of the faces
+    /// they belong to, in the order of [`Qh::facets`].
+    /// The map is built from the vertex sets of the faces,
+    /// in `O(sum of the number of vertices of each face)`.
+    /// For a single vertex, [`Vertex::adjacent_faces`](crate::Vertex::adjacent_faces) does not
+    /// need to build the map once the [vertex neighbors](Qh::compute_vertex_neighbors) are computed.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a tetrahedron
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// qh.compute_vertex_neighbors().unwrap();
+    ///
+    /// let map = qh.vertex_to_faces_map();
+    /// assert_eq!(map.len(), 4);
+    /// for vertex in qh.vertices() {
+    ///     let mut expected: Vec<_> = vertex.adjacent_faces().map(|f| f.id()).collect();
+    ///     let mut faces = map[&vertex.id()].clone();
+    ///     expected.sort();
+    ///     faces.sort();
+    ///     assert_eq!(faces, expected);
+    /// }
+    /// ```
+    pub fn vertex_to_faces_map(&self) -> HashMap<u32, Vec<u32>> {
+        let mut map: HashMap<u32, Vec<u32>> = HashMap::with_capacity(self.num_vertices());
+        for facet in self.facets() {
+            for vertex in facet.vertices() {
+                map.entry(vertex.id()).or_default().push(facet.id());
+            }
+        }
+        map
+    }
+
+    /// Euler characteristic `V - E + F` of the hull
+    ///
+    /// `V`, `E` and `F` are the numbers of vertices, [ridges](Qh::num_ridges) and faces.
+    ///
+    /// The boundary of a 3-d hull is a sphere, so the value is `2`, which makes it a cheap
+    /// sanity check of the topology of the hull.
+    /// In other dimensions the ridges are not the edges of the hull and the value
+    /// is not the Euler characteristic of the boundary.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use rand::{rngs::StdRng, Rng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// for _ in 0..10 {
+    ///     let points: Vec<[f64; 3]> = (0..100).map(|_| [rng.gen(), rng.gen(), rng.gen()]).collect();
+    ///     let qh = Qh::builder().build_from_iter(points).unwrap();
+    ///     assert_eq!(qh.euler_characteristic(), 2);
+    /// }
+    ///
+    /// // a cube, with non-simplicial faces
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// assert_eq!(qh.euler_characteristic(), 8 - 12 + 6);
+    /// ```
+    pub fn euler_characteristic(&self) -> i64 {
+        self.num_vertices() as i64 - self.num_ridges() as i64 + self.num_facets() as i64
+    }
+
+    /// Check that every ridge of the hull is shared by exactly two faces
+    ///
+    /// This is the manifold property of the boundary of the hull (a closed 2-manifold in 3-d).
+    /// The [explicit ridges](Qh::ridges) must have two distinct faces of the hull,
+    /// and since qhull does not store the ridges between simplicial faces,
+    /// the [neighbors](Facet::neighbors) of each face must be faces of the hull that have it
+    /// as a neighbor, with exactly `dim` neighbors for a simplicial face.
+    ///
+    /// A computed hull is expected to be manifold, so this is a sanity check
+    /// after modifications like [`Qh::add_point`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// assert!(qh.is_manifold());
+    ///
+    /// qh.add_point(&[0.5, 0.5, 2.0]).unwrap();
+    /// assert!(qh.is_manifold());
+    /// assert_eq!(qh.euler_characteristic(), 2);
+    /// ```
+    pub fn is_manifold(&self) -> bool {
+        let neighbors = self.dual_graph();
+        let is_face = |id: &u32| neighbors.contains_key(id);
+
+        let ridges_ok = self.ridges().all(|ridge| match ridge.adjacent_faces() {
+            (Some(top), Some(bottom)) => {
+                top.id() != bottom.id() && is_face(&top.id()) && is_face(&bottom.id())
+            }
+            _ => false,
+        });
+        let neighbors_ok = self.facets().all(|facet| {
+            let id = facet.id();
+            let face_neighbors = &neighbors[&id];
+            (!facet.simplicial() || face_neighbors.len() == self.dim)
+                && face_neighbors
+                    .iter()
+                    .all(|n| *n != id && neighbors.get(n).is_some_and(|back| back.contains(&id)))
+        });
+        ridges_ok && neighbors_ok
+    }
+
+    /// Iterate over the faces of the hull in breadth-first order from `start`
+    ///
+    /// The faces are visited through their [neighbors](Facet::neighbors),
+    /// `start` is the first face and each face is yielded once,
+    /// so that faces close to each other on the hull come close in the iteration.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let start = qh.facets().next().unwrap();
+    /// let faces: Vec<_> = qh.bfs_faces(start).collect();
+    /// assert_eq!(faces.len(), 6);
+    /// assert_eq!(faces[0].id(), start.id());
+    ///
+    /// // the 4 neighbors of the first face come before the opposite face
+    /// let last = faces[5].normal().unwrap();
+    /// let first = start.normal().unwrap();
+    /// assert!(last.iter().zip(first).all(|(a, b)| (a + b).abs() < 1e-12));
+    /// ```
+    pub fn bfs_faces<'b>(&'b self, start: Facet<'b>) -> impl Iterator<Item = Facet<'b>> + 'b {
+        let mut visited = HashSet::from([start.id()]);
+        let mut queue = VecDeque::from([start]);
+        std::iter::from_fn(move || {
+            let face = queue.pop_front()?;
+            for neighbor in face.neighbors().into_iter().flat_map(|s| s.iter()) {
+                if visited.insert(neighbor.id()) {
+                    queue.push_back(neighbor);
+                }
+            }
+            Some(face)
+        })
+    }
+}
@@ -0,0 +1,90 @@
+//! Halfspace representation of the hull
+
+use crate::Qh;
+
+/// A halfspace `{x : normal · x <= offset}`
+///
+/// Note that the sign convention of the offset is the opposite of qhull's
+/// (`normal · x + offset <= 0`, see [`Facet::offset`](crate::Facet::offset)
+/// and [`Qh::new_halfspace_intersection`]), the conversion to a `(normal, offset)` tuple
+/// for [`Qh::new_halfspace_intersection`] takes care of it.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Halfspace {
+    pub normal: Vec<f64>,
+    pub offset: f64,
+}
+
+impl Halfspace {
+    /// Signed distance of `point` from the boundary of the halfspace
+    ///
+    /// The value is negative inside the halfspace and positive outside,
+    /// it is a distance if the normal is a unit vector (e.g. for [`Qh::make_halfspaces`]).
+    ///
+    /// # Panics
+    /// * If `point` and the normal do not have the same dimension
+    pub fn signed_distance(&self, point: &[f64]) -> f64 {
+        assert_eq!(point.len(), self.normal.len(), "point and halfspace have different dimensions");
+        self.normal.iter().zip(point).map(|(n, x)| n * x).sum::<f64>() - self.offset
+    }
+
+    /// Check whether `point` is in the halfspace (`normal · point <= offset`)
+    ///
+    /// Points on the boundary might be classified either way because of rounding errors.
+    ///
+    /// # Panics
+    /// * If `point` and the normal do not have the same dimension
+    pub fn contains(&self, point: &[f64]) -> bool {
+        self.signed_distance(point) <= 0.0
+    }
+}
+
+/// Convert to the `(normal, offset)` convention of qhull, see [`Qh::new_halfspace_intersection`]
+impl From<Halfspace> for (Vec<f64>, f64) {
+    fn from(halfspace: Halfspace) -> Self {
+        (halfspace.normal, -halfspace.offset)
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// The hull as an intersection of halfspaces, one for each face
+    ///
+    /// Each halfspace is bounded by the hyperplane of a face
+    /// and its normal is the outward unit [normal](crate::Facet::normal) of the face,
+    /// so the points of the hull satisfy `normal · x <= offset` for every halfspace.
+    /// The faces without a normal are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 2.0],
+    ///         [2.0, 2.0],
+    ///     ]).unwrap();
+    ///
+    /// let halfspaces = qh.make_halfspaces();
+    /// assert_eq!(halfspaces.len(), 4);
+    /// assert!(halfspaces.iter().all(|h| h.contains(&[1.0, 1.0])));
+    /// assert!(!halfspaces.iter().all(|h| h.contains(&[3.0, 1.0])));
+    ///
+    /// // and back to the square
+    /// let qh = Qh::new_halfspace_intersection(
+    ///     halfspaces.into_iter().map(Into::into),
+    ///     vec![1.0, 1.0],
+    /// ).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// ```
+    pub fn make_halfspaces(&self) -> Vec<Halfspace> {
+        self.facets()
+            .filter_map(|facet| {
+                Some(Halfspace {
+                    normal: facet.normal()?.to_vec(),
+                    offset: -facet.offset()?,
+                })
+            })
+            .collect()
+    }
+}
@@ -94,6 +94,38 @@ where
 /// assert_eq!(count, 3);
 /// assert_eq!(dim, 2);
 /// ```
+///
+/// All the coordinates are scaled by the same factor (the largest half-width of the input),
+/// scaling the axes independently would give the Delaunay triangulation of stretched points.
+/// With very different extents along the axes, the circumcircle of each Delaunay triangle
+/// is still empty:
+/// ```
+/// # use qhull::*;
+/// let points: Vec<[f64; 2]> = (0..30)
+///     .map(|i| {
+///         let i = i as f64;
+///         [100.0 * (i * 0.618_034).fract(), (i * 0.414_214).fract()]
+///     })
+///     .collect();
+/// let qh = Qh::new_delaunay(points.iter().copied()).unwrap();
+///
+/// for simplex in qh.simplices().filter(|f| !f.upper_delaunay()) {
+///     let [a, b, c]: [&[f64]; 3] = simplex
+///         .vertices()
+///         .map(|v| v.point().unwrap())
+///         .collect::<Vec<_>>()
+///         .try_into()
+///         .unwrap();
+///     let sq = |p: &[f64]| p[0] * p[0] + p[1] * p[1];
+///     let d = 2.0 * (a[0] * (b[1] - c[1]) + b[0] * (c[1] - a[1]) + c[0] * (a[1] - b[1]));
+///     let x = (sq(a) * (b[1] - c[1]) + sq(b) * (c[1] - a[1]) + sq(c) * (a[1] - b[1])) / d;
+///     let y = (sq(a) * (c[0] - b[0]) + sq(b) * (a[0] - c[0]) + sq(c) * (b[0] - a[0])) / d;
+///     let radius_squared = (a[0] - x).powi(2) + (a[1] - y).powi(2);
+///     for p in &points {
+///         assert!((p[0] - x).powi(2) + (p[1] - y).powi(2) >= radius_squared * (1.0 - 1e-9));
+///     }
+/// }
+/// ```
 pub fn prepare_delaunay_points<I>(points: impl IntoIterator<Item = I>) -> CollectedCoords
 where
     I: IntoIterator<Item = f64>,
@@ -124,17 +156,20 @@ where
         }
     }
     center.iter_mut().for_each(|coord| *coord /= count as f64);
-    let widths: Vec<f64> = min_coords
+    // the same width is used for every coordinate:
+    // scaling the axes differently would change the Delaunay triangulation
+    let width = min_coords
         .iter()
         .zip(max_coords.iter())
         .map(|(min, max)| (max - min) / 2.0)
-        .collect();
+        .fold(0.0, f64::max);
+    let width = if width > 0.0 { width } else { 1.0 };
 
     // build paraboloid
     for point in 0..count {
         let point = &mut coords[point * dim..(point + 1) * dim];
         for i in 0..orig_dim {
-            let d = (point[i] - center[i]) / widths[i];
+            let d = (point[i] - center[i]) / width;
             point[orig_dim] += d * d;
         }
     }
@@ -142,6 +177,252 @@ where
     CollectedCoords { coords, count, dim }
 }
 
+/// Volume of a simplex given by `dim + 1` points of dimension `dim`
+///
+/// The volume is always non-negative.
+pub(crate) fn simplex_volume(points: &[&[f64]]) -> f64 {
+    let dim = points.len() - 1;
+    let origin = points[0];
+    let mut m: Vec<Vec<f64>> = points[1..]
+        .iter()
+        .map(|p| p.iter().zip(origin).map(|(a, b)| a - b).collect())
+        .collect();
+
+    // gaussian elimination with partial pivoting
+    let mut det = 1.0;
+    for col in 0..dim {
+        let pivot = (col..dim)
+            .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+            .unwrap();
+        if m[pivot][col] == 0.0 {
+            return 0.0;
+        }
+        if pivot != col {
+            m.swap(pivot, col);
+            det = -det;
+        }
+        det *= m[col][col];
+        let (top, bottom) = m.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        for row in bottom {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row.iter_mut().zip(pivot_row).skip(col) {
+                *x -= factor * p;
+            }
+        }
+    }
+
+    let factorial: f64 = (1..=dim).map(|i| i as f64).product();
+    det.abs() / factorial
+}
+
+/// Circumradius of a simplex
+///
+/// The simplex is given by its `k + 1` vertices, all of the same dimension `dim >= k`,
+/// so that, for example, the circumradius of a triangle embedded in 3D can be computed.
+/// Returns [`f64::INFINITY`] if the simplex is degenerate.
+///
+/// # Example
+/// ```
+/// # use qhull::helpers::*;
+/// // right triangle: the circumcenter is the midpoint of the hypotenuse
+/// let r = circumradius(&[&[0.0, 0.0], &[2.0, 0.0], &[0.0, 2.0]]);
+/// assert!((r - 2f64.sqrt()).abs() < 1e-12);
+///
+/// // degenerate triangle
+/// assert_eq!(circumradius(&[&[0.0, 0.0], &[1.0, 0.0], &[2.0, 0.0]]), f64::INFINITY);
+/// ```
+pub fn circumradius(simplex_vertices: &[&[f64]]) -> f64 {
+    let Some(center) = circumcenter(simplex_vertices) else {
+        return f64::INFINITY;
+    };
+    let origin = simplex_vertices[0];
+    center.iter().zip(origin).map(|(c, o)| (c - o).powi(2)).sum::<f64>().sqrt()
+}
+
+/// Circumcenter of a simplex, in the affine hull of its vertices
+///
+/// See [`circumradius`], returns `None` if the simplex is degenerate.
+pub(crate) fn circumcenter(simplex_vertices: &[&[f64]]) -> Option<Vec<f64>> {
+    let origin = simplex_vertices[0];
+    let edges: Vec<Vec<f64>> = simplex_vertices[1..]
+        .iter()
+        .map(|p| p.iter().zip(origin).map(|(a, b)| a - b).collect())
+        .collect();
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+    // the circumcenter is `origin + sum(l_i * e_i)` with `e_j · (center - origin) = |e_j|^2 / 2`
+    let mut m: Vec<Vec<f64>> = edges
+        .iter()
+        .map(|a| {
+            let mut row: Vec<f64> = edges.iter().map(|b| dot(a, b)).collect();
+            row.push(dot(a, a) / 2.0);
+            row
+        })
+        .collect();
+    let scale = m.iter().map(|row| row[row.len() - 1]).fold(0.0, f64::max);
+    let l = solve_augmented(&mut m, scale * 1e-12)?;
+
+    Some(
+        (0..origin.len())
+            .map(|i| origin[i] + edges.iter().zip(&l).map(|(e, l)| l * e[i]).sum::<f64>())
+            .collect(),
+    )
+}
+
+/// Closest point to `target` in the convex hull of a set of points
+///
+/// This is Wolfe's minimum norm point algorithm, which is exact (up to rounding errors)
+/// and works for any number of points in any dimension,
+/// so that for example the closest point on a facet of a hull can be computed from its vertices.
+///
+/// # Panics
+/// * If `points` is empty
+///
+/// # Example
+/// ```
+/// # use qhull::helpers::*;
+/// let square: [&[f64]; 4] = [&[0.0, 0.0], &[1.0, 0.0], &[0.0, 1.0], &[1.0, 1.0]];
+///
+/// // the closest point is on an edge
+/// let p = closest_point_in_convex_hull(&square, &[0.5, 3.0]);
+/// assert!((p[0] - 0.5).abs() < 1e-12 && (p[1] - 1.0).abs() < 1e-12);
+///
+/// // the closest point is a vertex
+/// let p = closest_point_in_convex_hull(&square, &[2.0, -1.0]);
+/// assert!((p[0] - 1.0).abs() < 1e-12 && p[1].abs() < 1e-12);
+///
+/// // points inside the hull are their own closest point
+/// let p = closest_point_in_convex_hull(&square, &[0.25, 0.5]);
+/// assert!((p[0] - 0.25).abs() < 1e-12 && (p[1] - 0.5).abs() < 1e-12);
+/// ```
+pub fn closest_point_in_convex_hull(points: &[&[f64]], target: &[f64]) -> Vec<f64> {
+    assert!(!points.is_empty(), "no points");
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+    // move the target to the origin, the closest point is then the min norm point
+    let shifted: Vec<Vec<f64>> = points
+        .iter()
+        .map(|p| p.iter().zip(target).map(|(a, b)| a - b).collect())
+        .collect();
+    let tolerance = shifted.iter().map(|p| dot(p, p)).fold(0.0, f64::max) * 1e-12;
+    let combine = |set: &[usize], lambda: &[f64]| -> Vec<f64> {
+        (0..target.len())
+            .map(|i| set.iter().zip(lambda).map(|(&j, l)| l * shifted[j][i]).sum())
+            .collect()
+    };
+
+    let nearest = (0..shifted.len())
+        .min_by(|&a, &b| dot(&shifted[a], &shifted[a]).total_cmp(&dot(&shifted[b], &shifted[b])))
+        .unwrap();
+    let mut set = vec![nearest];
+    let mut lambda = vec![1.0];
+    let mut x = shifted[nearest].clone();
+
+    // each major cycle strictly decreases |x|, the bound only guards against rounding issues
+    for _ in 0..100 * (shifted.len() + target.len()) {
+        let (j, xj) = (0..shifted.len())
+            .map(|j| (j, dot(&x, &shifted[j])))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        if dot(&x, &x) - xj <= tolerance || set.contains(&j) {
+            break;
+        }
+        set.push(j);
+        lambda.push(0.0);
+
+        loop {
+            let Some(mu) = affine_min_norm(&shifted, &set, tolerance) else {
+                // affinely dependent points, keep the current solution
+                set.pop();
+                lambda.pop();
+                return x.iter().zip(target).map(|(a, b)| a + b).collect();
+            };
+            if mu.iter().all(|&m| m > 0.0) {
+                lambda = mu;
+                break;
+            }
+            // move towards the affine minimum until a coefficient becomes zero
+            let (k, theta) = lambda
+                .iter()
+                .zip(&mu)
+                .enumerate()
+                .filter(|(_, (_, &m))| m <= 0.0)
+                .map(|(k, (&l, &m))| (k, l / (l - m)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .unwrap();
+            for (l, m) in lambda.iter_mut().zip(&mu) {
+                *l = theta * m + (1.0 - theta) * *l;
+            }
+            lambda[k] = 0.0;
+            let mut i = 0;
+            set.retain(|_| {
+                i += 1;
+                lambda[i - 1] > 0.0
+            });
+            lambda.retain(|&l| l > 0.0);
+        }
+        x = combine(&set, &lambda);
+    }
+    x.iter().zip(target).map(|(a, b)| a + b).collect()
+}
+
+/// Barycentric coordinates of the min norm point of the affine hull of `points[set]`
+fn affine_min_norm(points: &[Vec<f64>], set: &[usize], tolerance: f64) -> Option<Vec<f64>> {
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+    let origin = &points[set[0]];
+    let edges: Vec<Vec<f64>> = set[1..]
+        .iter()
+        .map(|&j| points[j].iter().zip(origin).map(|(a, b)| a - b).collect())
+        .collect();
+
+    // the point is `origin + sum(a_i * e_i)` with `e_j · point = 0`
+    let mut m: Vec<Vec<f64>> = edges
+        .iter()
+        .map(|a| {
+            let mut row: Vec<f64> = edges.iter().map(|b| dot(a, b)).collect();
+            row.push(-dot(a, origin));
+            row
+        })
+        .collect();
+    let a = solve_augmented(&mut m, tolerance)?;
+    let mut mu = vec![1.0 - a.iter().sum::<f64>()];
+    mu.extend(a);
+    Some(mu)
+}
+
+/// Solves the linear system given by an augmented `n x (n + 1)` matrix
+///
+/// Returns `None` if a pivot is not larger than `tolerance` in absolute value.
+fn solve_augmented(m: &mut [Vec<f64>], tolerance: f64) -> Option<Vec<f64>> {
+    let n = m.len();
+    // gaussian elimination with partial pivoting
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+            .unwrap();
+        if m[pivot][col].abs() <= tolerance {
+            return None;
+        }
+        m.swap(pivot, col);
+        let (top, bottom) = m.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        for row in bottom {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row.iter_mut().zip(pivot_row).skip(col) {
+                *x -= factor * p;
+            }
+        }
+    }
+
+    // back substitution
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|j| m[row][j] * x[j]).sum();
+        x[row] = (m[row][n] - sum) / m[row][row];
+    }
+    Some(x)
+}
+
 pub struct CArgs {
     args: Vec<CString>,
     args_ptr: Vec<*const c_char>,
@@ -3,6 +3,9 @@ use crate::{sys, tmp_file::TmpFile};
 pub struct IOBuffers {
     pub out_file: Option<TmpFile>,
     pub err_file: Option<TmpFile>,
+    /// Level of the messages of the captured stderr, see [`IOBuffers::log_messages`]
+    #[cfg(feature = "log")]
+    pub(crate) log_level: log::Level,
 }
 
 impl IOBuffers {
@@ -12,6 +15,8 @@ impl IOBuffers {
                 .then(|| TmpFile::new().expect("failed to create temporary file for stdout")),
             err_file: capture_stderr
                 .then(|| TmpFile::new().expect("failed to create temporary file for stderr")),
+            #[cfg(feature = "log")]
+            log_level: log::Level::Debug,
         }
     }
 
@@ -32,4 +37,42 @@ impl IOBuffers {
             |f| f.file_handle() as *mut _,
         )
     }
+
+    /// Emit the messages written by qhull to the captured stderr with the `log` crate
+    ///
+    /// The captured file is replaced by an empty one, so that each message is emitted once.
+    ///
+    /// # Safety
+    /// * `qh` must be the instance writing to these buffers
+    #[cfg(feature = "log")]
+    pub(crate) unsafe fn log_messages(&mut self, qh: *mut sys::qhT) {
+        let Some(file) = self.err_file.as_ref() else {
+            return;
+        };
+        if unsafe { sys::ftell(file.file_handle()) } <= 0 {
+            return;
+        }
+
+        let replacement = match TmpFile::new() {
+            Ok(file) => file,
+            Err(e) => {
+                // the messages stay in the current file and are emitted by the next call
+                log::warn!("failed to create temporary file for qhull messages: {e}");
+                return;
+            }
+        };
+        unsafe {
+            (*qh).ferr = replacement.file_handle();
+            (*qh).qhmem.ferr = replacement.file_handle();
+        }
+        let file = self.err_file.replace(replacement).unwrap();
+        match file.read_as_string_and_close() {
+            Ok(messages) => {
+                for line in messages.lines().filter(|l| !l.trim().is_empty()) {
+                    log::log!(self.log_level, "{line}");
+                }
+            }
+            Err(e) => log::warn!("failed to read qhull messages: {e}"),
+        }
+    }
 }
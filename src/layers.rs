@@ -0,0 +1,72 @@
+//! Convex layers of a set of points
+
+use crate::{helpers::{collect_coords, CollectedCoords}, Qh, QhBuilder};
+
+impl<'a> Qh<'a> {
+    /// Compute the convex layers of a set of points (onion peeling)
+    ///
+    /// The first layer contains the vertices of the convex hull of the points,
+    /// the second layer the vertices of the hull of the remaining points, and so on.
+    /// Each layer lists the indices of its points in the input, in increasing order,
+    /// and a point belongs to at most one layer: the depth of a point is the index of its layer.
+    ///
+    /// The peeling stops when fewer than `dim + 1` points remain: these points form the last layer.
+    /// It also stops when qhull fails on the remaining points (e.g. because they are not
+    /// full-dimensional), these points are then left out of the layers.
+    ///
+    /// # Panics
+    /// * If the points do not have the same dimension or there are no points
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let layers = Qh::convex_layers([
+    ///     [-2.0, -2.0], [2.0, -2.0], [2.0, 2.0], [-2.0, 2.0],
+    ///     [-1.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.0, -1.0],
+    ///     [0.1, 0.1],
+    /// ]);
+    /// assert_eq!(layers, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8]]);
+    ///
+    /// // the points inside the square are collinear, their hull cannot be computed
+    /// let layers = Qh::convex_layers([
+    ///     [-2.0, -2.0], [2.0, -2.0], [2.0, 2.0], [-2.0, 2.0],
+    ///     [-1.0, 0.0], [0.0, 0.0], [1.0, 0.0],
+    /// ]);
+    /// assert_eq!(layers, vec![vec![0, 1, 2, 3]]);
+    /// ```
+    pub fn convex_layers<I>(points: impl IntoIterator<Item = I>) -> Vec<Vec<usize>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let CollectedCoords { coords, count, dim } = collect_coords(points);
+
+        let mut layers = Vec::new();
+        let mut remaining: Vec<usize> = (0..count).collect();
+        while !remaining.is_empty() {
+            if remaining.len() < dim + 1 {
+                layers.push(remaining);
+                break;
+            }
+
+            let layer_coords: Vec<f64> = remaining
+                .iter()
+                .flat_map(|&i| coords[i * dim..(i + 1) * dim].iter().copied())
+                .collect();
+            let Ok(qh) = QhBuilder::default().build_managed(dim, layer_coords) else {
+                break;
+            };
+
+            let mut on_hull = vec![false; remaining.len()];
+            for index in qh.vertices().filter_map(|v| v.index(&qh)) {
+                on_hull[index] = true;
+            }
+            let (layer, rest) = remaining
+                .iter()
+                .zip(on_hull)
+                .partition::<Vec<_>, _>(|(_, on_hull)| *on_hull);
+            layers.push(layer.into_iter().map(|(&i, _)| i).collect());
+            remaining = rest.into_iter().map(|(&i, _)| i).collect();
+        }
+        layers
+    }
+}
@@ -1,6 +1,6 @@
 #![doc = include_str!("../README.md")]
 
-use std::{cell::{RefCell, UnsafeCell}, marker::PhantomData, rc::Rc};
+use std::{cell::{RefCell, UnsafeCell}, collections::HashMap, iter::FusedIterator, marker::PhantomData, ops::Not, sync::Arc};
 
 use helpers::{prepare_delaunay_points, CollectedCoords, QhTypeRef};
 use io_buffers::IOBuffers;
@@ -15,6 +15,36 @@ mod builder;
 pub use builder::*;
 mod types;
 pub use types::*;
+mod snapshot;
+pub use snapshot::*;
+mod mesh;
+pub use mesh::*;
+mod formats;
+pub use formats::*;
+mod send;
+pub use send::*;
+mod query;
+pub use query::*;
+mod delaunay;
+mod voronoi;
+mod stats;
+pub use stats::*;
+mod graph;
+pub use graph::*;
+mod halfspace;
+pub use halfspace::*;
+mod layers;
+mod clip;
+mod mass;
+pub use mass::*;
+mod enclosing;
+mod pairwise;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_support;
+#[cfg(feature = "ndarray")]
+mod ndarray_support;
+#[cfg(feature = "glam")]
+mod glam_support;
 pub mod examples;
 
 /// A Qhull instance
@@ -29,6 +59,12 @@ pub struct Qh<'a> {
     dim: usize,
     buffers: RefCell<IOBuffers>,
     owned_values: OwnedValues,
+    /// Settings used to build the instance, see [`Qh::reset_with_points`]
+    builder: Option<QhBuilder>,
+    /// Coordinates of the points added with [`Qh::add_point`]
+    added_points: Vec<Box<[f64]>>,
+    /// Options passed to `qh_initflags`, see [`Qh::hull_option_string`]
+    option_string: String,
     phantom: PhantomData<&'a ()>,
 }
 
@@ -38,23 +74,313 @@ impl<'a> Qh<'a> {
         QhBuilder::default()
     }
 
+    /// Replace the points of the instance and compute the new hull
+    ///
+    /// The hull is freed and the instance is initialized again with the same settings
+    /// it was built with (see [`QhBuilder`]), but qhull's memory pools are kept,
+    /// which saves the allocations of a new instance when hulls are computed in a loop.
+    /// Like [`QhBuilder::build_managed`], the new points are owned by the instance.
+    ///
+    /// The instances created with [`Qh::new_delaunay`], [`Qh::new_furthest_site_delaunay`]
+    /// and [`Qh::new_voronoi`] take points of the input dimension (one less than the
+    /// [hull dimension](Qh::hull_dim)): they are lifted with [`helpers::prepare_delaunay_points`]
+    /// like in the constructor, and the Voronoi centers are computed again.
+    ///
+    /// If qhull fails, the instance is left without a hull and can be reset again.
+    ///
+    /// # Remarks
+    /// * a computation that fails is not joggled again (see [`QhBuilder::joggle_retry`])
+    ///
+    /// # Errors
+    /// * If `points.len()` is not a multiple of `dim`
+    ///   or `dim` does not match the [dimension hint](QhBuilder::dim)
+    /// * If qhull fails to compute the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// for n in 4..10 {
+    ///     // a regular polygon with n sides
+    ///     let points = (0..n)
+    ///         .map(|i| i as f64 * std::f64::consts::TAU / n as f64)
+    ///         .flat_map(|a| [a.cos(), a.sin()])
+    ///         .collect();
+    ///     qh.reset_with_points(2, points).unwrap();
+    ///     assert_eq!(qh.num_facets(), n);
+    ///     assert_eq!(qh.num_input_points(), n);
+    /// }
+    ///
+    /// // also with a different dimension
+    /// qh.reset_with_points(3, vec![
+    ///     0.0, 0.0, 0.0,
+    ///     1.0, 0.0, 0.0,
+    ///     0.0, 1.0, 0.0,
+    ///     0.0, 0.0, 1.0,
+    /// ]).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    ///
+    /// assert!(qh.reset_with_points(2, vec![0.0, 0.0, 1.0]).is_err());
+    /// ```
+    ///
+    /// With a Delaunay triangulation, the new points are triangulated like the first ones:
+    /// ```
+    /// # use qhull::*;
+    /// // a triangle with a point inside
+    /// let triangle = [[0.0, 0.0], [4.0, 0.0], [0.0, 3.0], [1.0, 1.0]];
+    /// let mut qh = Qh::new_delaunay(triangle).unwrap();
+    /// assert_eq!(qh.simplices().count(), 3);
+    ///
+    /// // a square with its center
+    /// qh.reset_with_points(2, vec![
+    ///     0.0, 0.0,
+    ///     1.0, 0.0,
+    ///     0.0, 1.0,
+    ///     1.0, 1.0,
+    ///     0.5, 0.5,
+    /// ]).unwrap();
+    /// assert_eq!(qh.hull_dim(), 3);
+    /// assert_eq!(qh.num_input_points(), 5);
+    /// assert_eq!(qh.simplices().count(), 4);
+    /// assert!(qh.simplices().all(|f| f.vertices().any(|v| v.index(&qh) == Some(4))));
+    ///
+    /// let mut voronoi = Qh::new_voronoi(triangle).unwrap();
+    /// voronoi.reset_with_points(2, vec![
+    ///     0.0, 0.0,
+    ///     1.0, 0.0,
+    ///     0.0, 1.0,
+    ///     1.0, 1.0,
+    ///     0.5, 0.5,
+    /// ]).unwrap();
+    /// assert_eq!(voronoi.voronoi_vertices().count(), 4);
+    /// ```
+    pub fn reset_with_points(&mut self, dim: usize, points: Vec<f64>) -> Result<(), QhError<'_>> {
+        let builder = self.builder.take().expect("instances are created by a QhBuilder");
+        let result = builder.reset(self, dim, points);
+        self.builder = Some(builder);
+        result
+    }
+
+    /// Add a point to a computed hull
+    ///
+    /// The point is located with [`qh_findbestfacet`](sys::qh_findbestfacet):
+    /// if it is outside the hull, it is added with [`qh_addpoint`](sys::qh_addpoint)
+    /// and `true` is returned, otherwise the hull is left unchanged and `false` is returned.
+    ///
+    /// The coordinates are copied and owned by the instance.
+    /// The added points are not input points: [`Vertex::index`] is `None` for their vertices
+    /// and they are not counted by [`Qh::num_input_points`].
+    ///
+    /// # Remarks
+    /// * the point is given as it is to qhull, in the coordinates of the hull:
+    ///   it is not transformed like the input points (e.g. lifted for a Delaunay triangulation,
+    ///   or rotated and scaled when these options are enabled)
+    ///
+    /// # Errors
+    /// * If the hull has not been computed
+    /// * If `point.len()` is not the dimension of the hull
+    /// * If qhull fails to add the point
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// assert!(!qh.add_point(&[0.25, 0.25]).unwrap());
+    /// assert_eq!(qh.num_vertices(), 3);
+    /// assert!((qh.volume().unwrap() - 0.5).abs() < 1e-12);
+    ///
+    /// assert!(qh.add_point(&[1.0, 1.0]).unwrap());
+    /// assert_eq!(qh.num_vertices(), 4);
+    /// assert_eq!(qh.num_facets(), 4);
+    /// assert!((qh.volume().unwrap() - 1.0).abs() < 1e-12);
+    /// assert!(qh.vertices().any(|v| v.coordinates() == Some(&[1.0, 1.0][..]) && v.index(&qh).is_none()));
+    ///
+    /// let added = qh.add_points([[2.0, 0.5], [0.5, 0.5], [-1.0, -1.0]]).unwrap();
+    /// assert_eq!(added, 2);
+    /// assert_eq!(qh.num_vertices(), 4);
+    /// assert!(qh.is_point_inside(&[1.5, 0.5]).unwrap());
+    ///
+    /// assert!(qh.add_point(&[1.0, 2.0, 3.0]).is_err());
+    /// ```
+    pub fn add_point(&mut self, point: &[f64]) -> Result<bool, QhError<'_>> {
+        if !self.is_computed() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has not been computed yet",
+            ));
+        }
+        if point.len() != self.dim {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("the point has {} coordinates, expected {}", point.len(), self.dim),
+            ));
+        }
+
+        let mut point: Box<[f64]> = point.into();
+        let point_ptr = point.as_mut_ptr();
+        let (facet, is_outside) = unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                let mut best_dist = 0.0;
+                let mut is_outside = 0;
+                let facet = sys::qh_findbestfacet(qh, point_ptr, false as _, &mut best_dist, &mut is_outside);
+                (facet, is_outside != 0)
+            })
+            .map_err(|e| e.into_static())?
+        };
+        if !is_outside {
+            return Ok(false);
+        }
+
+        // qhull keeps a pointer to the coordinates in the new vertex
+        self.added_points.push(point);
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_addpoint(qh, point_ptr, facet, false as _);
+                // the areas and the volume are computed again when needed
+                (*qh).hasAreaVolume = false as _;
+            })?;
+        }
+        Ok(true)
+    }
+
+    /// Add several points to a computed hull
+    ///
+    /// Returns the number of points that were outside the hull when they were added,
+    /// see [`Qh::add_point`].
+    pub fn add_points<I>(&mut self, points: impl IntoIterator<Item = I>) -> Result<usize, QhError<'_>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let mut added = 0;
+        for point in points {
+            let point: Vec<f64> = point.into_iter().collect();
+            if self.add_point(&point).map_err(|e| e.into_static())? {
+                added += 1;
+            }
+        }
+        Ok(added)
+    }
+
     /// Compute the convex hull
+    ///
+    /// With the `tracing` feature enabled, the computation is recorded in a span,
+    /// with events for its start, its result and the elapsed time.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub fn compute(&mut self) -> Result<(), QhError> {
-        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_qhull(qh)) }
+        // a raw pointer, since an error borrows the instance
+        #[cfg(feature = "tracing")]
+        let (raw, start) = {
+            tracing::debug!(num_input_points = self.num_input_points(), dim = self.dim, "computing the hull");
+            (self.qh.get() as *const sys::qhT, std::time::Instant::now())
+        };
+
+        let result = unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_qhull(qh)) };
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(()) => unsafe {
+                tracing::info!(
+                    num_faces = sys::qh_get_num_facets(raw),
+                    num_vertices = sys::qh_get_num_vertices(raw),
+                    elapsed = ?start.elapsed(),
+                    "hull computed",
+                );
+            },
+            Err(error) => tracing::error!(%error, "failed to compute the hull"),
+        }
+        result
+    }
+
+    /// Compute the convex hull, failing if it takes longer than `timeout`
+    ///
+    /// Like [`Qh::compute`], but the computation is reported as failed when it took longer than
+    /// `timeout`, which is useful to reject untrusted or pathological inputs
+    /// (e.g. in servers or fuzzing harnesses).
+    ///
+    /// qhull cannot be interrupted safely from another thread
+    /// (the options it checks while adding points are not synchronized),
+    /// so the computation always runs to completion on the calling thread
+    /// and the timeout is checked afterwards: it does not bound the time spent in this function.
+    /// Use [`QhBuilder::stop_add`] to bound the work done by qhull.
+    ///
+    /// # Errors
+    /// * If qhull fails to compute the hull
+    /// * If the computation took longer than `timeout`, with kind [`QhErrorKind::OtherError`].
+    ///   The hull is complete in this case
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::time::Duration;
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ];
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    ///
+    /// qh.compute_with_timeout(Duration::from_secs(60)).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    /// let error = qh.compute_with_timeout(Duration::ZERO).unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::OtherError);
+    /// ```
+    pub fn compute_with_timeout(&mut self, timeout: std::time::Duration) -> Result<(), QhError<'_>> {
+        let start = std::time::Instant::now();
+        self.compute().map_err(|e| e.into_static())?;
+        let elapsed = start.elapsed();
+        if elapsed > timeout {
+            return Err(QhError::from_message(
+                QhErrorKind::OtherError,
+                format!("the computation took {elapsed:?}, more than {timeout:?}"),
+            ));
+        }
+        Ok(())
     }
 
     /// Check the output of the qhull instance
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub fn check_output(&mut self) -> Result<(), QhError> {
-        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_check_output(qh)) }
+        let result = unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_check_output(qh)) };
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            tracing::error!(%error, "the output check failed");
+        }
+        result
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub fn check_points(&mut self) -> Result<(), QhError> {
-        unsafe {
-            Qh::try_on_qh_mut(self, |qh| {
-                println!("qh_check_points!!!");
-                sys::qh_check_points(qh)
-            })
+        let result = unsafe {
+            Qh::try_on_qh_mut(self, |qh| sys::qh_check_points(qh))
+        };
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            tracing::error!(%error, "the points check failed");
         }
+        result
     }
 
     /// Creates a new Delaunay triangulation
@@ -72,14 +398,206 @@ impl<'a> Qh<'a> {
 
         // TODO check correctness, use qdelaunay as reference
         QhBuilder::default()
+            .construction(Construction::Delaunay)
             .delaunay(true)
-            .upper_delaunay(true)
             .scale_last(true)
             .triangulate(true)
             .keep_coplanar(true)
             .build_managed(dim, coords)
     }
 
+    /// Creates a new furthest-site Delaunay triangulation
+    ///
+    /// Unlike [`Qh::new_delaunay`], whose simplices are the lower facets of the lifted hull
+    /// (the circumsphere of each simplex contains no other input point),
+    /// the furthest-site simplices are the upper facets (the circumsphere of each simplex
+    /// contains all the input points). Like in Qhull's `qdelaunay Qu`, the hull is computed
+    /// with the `Qu` option, and [`Qh::simplices`] only returns the upper facets,
+    /// the facets for which [`Facet::upper_delaunay`] is `true`.
+    ///
+    /// Only the points on the convex hull of the input are vertices of the furthest-site triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a triangle with a point inside
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [4.0, 0.0],
+    ///     [0.0, 3.0],
+    ///     [1.0, 1.0],
+    /// ];
+    /// let triangles = |qh: &Qh| {
+    ///     let mut triangles = qh
+    ///         .simplices()
+    ///         .map(|f| {
+    ///             let mut indices = f.vertices().map(|v| v.index(qh).unwrap()).collect::<Vec<_>>();
+    ///             indices.sort();
+    ///             indices
+    ///         })
+    ///         .collect::<Vec<_>>();
+    ///     triangles.sort();
+    ///     triangles
+    /// };
+    ///
+    /// let nearest = Qh::new_delaunay(points).unwrap();
+    /// assert!(nearest.simplices().all(|f| !f.upper_delaunay()));
+    /// assert_eq!(triangles(&nearest), vec![vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]]);
+    ///
+    /// let furthest = Qh::new_furthest_site_delaunay(points).unwrap();
+    /// assert_eq!(furthest.hull_option_string(), "Qu");
+    /// assert!(furthest.simplices().all(|f| f.upper_delaunay()));
+    /// assert_eq!(triangles(&furthest), vec![vec![0, 1, 2]]);
+    /// ```
+    pub fn new_furthest_site_delaunay<I>(
+        points: impl IntoIterator<Item = I>,
+    ) -> Result<Self, QhError<'static>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let CollectedCoords {
+            coords,
+            count: _,
+            dim,
+        } = prepare_delaunay_points(points);
+
+        QhBuilder::default()
+            .construction(Construction::Delaunay)
+            .with_flags("Qu")
+            .delaunay(true)
+            .scale_last(true)
+            .triangulate(true)
+            .keep_coplanar(true)
+            .build_managed(dim, coords)
+    }
+
+    /// Creates a new Voronoi diagram
+    ///
+    /// The diagram is computed as the dual of the [Delaunay triangulation](Qh::new_delaunay):
+    /// the Voronoi centers of the facets are computed with [`qh_setvoronoi_all`](sys::qh_setvoronoi_all).
+    /// Use [`Qh::voronoi_vertices`] and [`Qh::voronoi_edges`] to access the diagram.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a square with its center
+    /// let qh = Qh::new_voronoi([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [1.0, 1.0],
+    ///     [0.5, 0.5],
+    /// ]).unwrap();
+    ///
+    /// let mut vertices = qh.voronoi_vertices().collect::<Vec<_>>();
+    /// assert_eq!(vertices.len(), 4);
+    /// vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// let expected = [[0.0, 0.5], [0.5, 0.0], [0.5, 1.0], [1.0, 0.5]];
+    /// for (v, e) in vertices.iter().zip(expected) {
+    ///     assert!((v[0] - e[0]).abs() < 1e-12 && (v[1] - e[1]).abs() < 1e-12);
+    /// }
+    ///
+    /// // the bounded cell of the center is a diamond
+    /// assert_eq!(qh.voronoi_edges().count(), 4);
+    /// ```
+    pub fn new_voronoi<I>(points: impl IntoIterator<Item = I>) -> Result<Self, QhError<'static>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let CollectedCoords {
+            coords,
+            count: _,
+            dim,
+        } = prepare_delaunay_points(points);
+
+        QhBuilder::default()
+            .construction(Construction::Voronoi)
+            .voronoi(true)
+            .delaunay(true)
+            .scale_last(true)
+            .triangulate(true)
+            .keep_coplanar(true)
+            .build_managed(dim, coords)
+    }
+
+    /// Creates a new halfspace intersection
+    ///
+    /// Each halfspace is given as `(normal, offset)` and represents the points `x`
+    /// such that `normal · x + offset <= 0` (Qhull convention).
+    /// The `feasible_point` must be clearly inside all the halfspaces.
+    ///
+    /// Qhull computes the intersection through its dual: each halfspace is mapped to the point
+    /// `normal / -(normal · feasible_point + offset)` and the convex hull of these points is computed.
+    /// Each facet of the resulting hull corresponds to a vertex of the intersection,
+    /// located at `feasible_point - facet_normal / facet_offset`.
+    ///
+    /// # Panics
+    /// * If the normals and the feasible point do not have the same dimension
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // the square [-1, 1]^2
+    /// let qh = Qh::new_halfspace_intersection(
+    ///     [
+    ///         (vec![1.0, 0.0], -1.0),
+    ///         (vec![-1.0, 0.0], -1.0),
+    ///         (vec![0.0, 1.0], -1.0),
+    ///         (vec![0.0, -1.0], -1.0),
+    ///     ],
+    ///     vec![0.0, 0.0],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(qh.num_facets(), 4);
+    /// for facet in qh.facets() {
+    ///     let normal = facet.normal().unwrap();
+    ///     let offset = facet.offset().unwrap();
+    ///     let vertex: Vec<f64> = normal.iter().map(|n| -n / offset).collect();
+    ///     assert!((vertex[0].abs() - 1.0).abs() < 1e-12);
+    ///     assert!((vertex[1].abs() - 1.0).abs() < 1e-12);
+    /// }
+    ///
+    /// // the feasible point must be inside
+    /// assert!(Qh::new_halfspace_intersection([(vec![1.0, 0.0], -1.0)], vec![2.0, 0.0]).is_err());
+    /// ```
+    pub fn new_halfspace_intersection(
+        halfspaces: impl IntoIterator<Item = (Vec<f64>, f64)>,
+        feasible_point: Vec<f64>,
+    ) -> Result<Self, QhError<'static>> {
+        let dim = feasible_point.len();
+        let mut coords = Vec::new();
+        for (normal, offset) in halfspaces {
+            assert_eq!(normal.len(), dim, "halfspace normal and feasible point have different dimensions");
+            let dist = offset + normal.iter().zip(&feasible_point).map(|(n, p)| n * p).sum::<f64>();
+            if dist >= 0.0 {
+                return Err(QhError::from_message(
+                    QhErrorKind::Input,
+                    format!("feasible point is not clearly inside halfspace {normal:?} at offset {offset}"),
+                ));
+            }
+            coords.extend(normal.iter().map(|n| n / -dist));
+        }
+
+        let builder = QhBuilder::default()
+            .dim(dim)
+            .half_space(true);
+        // qhull releases `feasible_point` with `qh_free` when the instance is freed,
+        // so it has to live in memory allocated by `qh_malloc`
+        let builder = unsafe {
+            builder.with_configure(move |qh| {
+                let feasible_point = feasible_point.clone();
+                Qh::try_on_qh_mut(qh, move |qh| {
+                    let ptr = sys::qh_malloc((feasible_point.len() * std::mem::size_of::<f64>()) as _) as *mut f64;
+                    if !ptr.is_null() {
+                        std::ptr::copy_nonoverlapping(feasible_point.as_ptr(), ptr, feasible_point.len());
+                    }
+                    (*qh).feasible_point = ptr;
+                })
+            })
+        };
+        builder.build_managed(dim, coords)
+    }
+
     /// Get all the facets in the hull
     ///
     /// # Remarks
@@ -118,8 +636,18 @@ impl<'a> Qh<'a> {
     /// # Remarks
     /// * this function will not return the sentinel face, which is the last face in the list of facets.
     ///   To get it, use the [`Qh::all_facets`] function.
-    pub fn facets(&self) -> impl Iterator<Item = Facet> {
-        self.all_facets().filter(|f| !f.is_sentinel())
+    /// * the iterator is [exact size](ExactSizeIterator), its length is [`Qh::num_facets`]
+    /// * the iterator is [double ended](DoubleEndedIterator): `facets().rev()` walks the list from the tail
+    pub fn facets(&self) -> impl DoubleEndedIterator<Item = Facet<'_>> + ExactSizeIterator + FusedIterator {
+        let head = Facet::from_ptr(
+            unsafe { sys::qh_get_facet_list(self.qh.get() as *mut _) },
+            self.dim,
+        );
+        let tail = Facet::from_ptr(
+            unsafe { sys::qh_get_facet_tail(self.qh.get() as *mut _) },
+            self.dim,
+        );
+        ListIterator::new(head, tail, self.num_facets())
     }
 
     pub fn all_vertices(&self) -> impl Iterator<Item = Vertex> {
@@ -146,8 +674,325 @@ impl<'a> Qh<'a> {
         }))
     }
 
-    pub fn vertices(&self) -> impl Iterator<Item = Vertex> {
-        self.all_vertices().filter(|v| !v.is_sentinel())
+    /// Get the vertices in the hull
+    ///
+    /// # Remarks
+    /// * this function will not return the sentinel vertex, which is the last vertex in the list of vertices.
+    ///   To get it, use the [`Qh::all_vertices`] function.
+    /// * the iterator is [exact size](ExactSizeIterator), its length is [`Qh::num_vertices`]
+    /// * the iterator is [double ended](DoubleEndedIterator): `vertices().rev()` walks the list from the tail
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// let vertices = qh.vertices();
+    /// assert_eq!(vertices.len(), 3);
+    /// let mut ids = Vec::with_capacity(qh.facets().len());
+    /// ids.extend(qh.facets().map(|f| f.id()));
+    /// assert_eq!(ids.len(), 3);
+    ///
+    /// let mut rev_ids = qh.facets().rev().map(|f| f.id()).collect::<Vec<_>>();
+    /// rev_ids.reverse();
+    /// assert_eq!(ids, rev_ids);
+    ///
+    /// // both ends meet in the middle
+    /// let mut facets = qh.facets();
+    /// let first = facets.next().unwrap();
+    /// let last = facets.next_back().unwrap();
+    /// assert_ne!(first.id(), last.id());
+    /// assert_eq!(facets.len(), 1);
+    /// assert!(facets.next().is_some());
+    /// assert!(facets.next_back().is_none());
+    /// ```
+    pub fn vertices(&self) -> impl DoubleEndedIterator<Item = Vertex<'_>> + ExactSizeIterator + FusedIterator {
+        let head = Vertex::from_ptr(
+            unsafe { sys::qh_get_vertex_list(self.qh.get() as *mut _) },
+            self.dim,
+        );
+        let tail = Vertex::from_ptr(
+            unsafe { sys::qh_get_vertex_tail(self.qh.get() as *mut _) },
+            self.dim,
+        );
+        ListIterator::new(head, tail, self.num_vertices())
+    }
+
+    /// Iterate over the facets together with the indices of their vertices in the input points
+    ///
+    /// This is equivalent to mapping [`Vertex::index`] over the [vertices](Facet::vertices)
+    /// of each facet, but the location of the input points is only looked up once.
+    /// Vertices that do not belong to the input points are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// for (facet, indices) in qh.faces_with_vertex_indices() {
+    ///     let expected = facet.vertices().map(|v| v.index(&qh).unwrap()).collect::<Vec<_>>();
+    ///     assert_eq!(indices, expected);
+    ///     assert!(!indices.contains(&3));
+    /// }
+    /// ```
+    pub fn faces_with_vertex_indices(&self) -> impl Iterator<Item = (Facet<'_>, Vec<usize>)> + '_ {
+        let point_index = self.point_index_lookup();
+        self.facets().map(move |facet| {
+            let indices = facet
+                .vertices()
+                .filter_map(|v| v.point())
+                .filter_map(point_index)
+                .collect();
+            (facet, indices)
+        })
+    }
+
+    /// The indices of the vertices of all the facets, in the input points
+    ///
+    /// Same as collecting the indices of [`Qh::faces_with_vertex_indices`],
+    /// the facets are in the same order as [`Qh::facets`].
+    /// Vertices that do not belong to the input points are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [0.1, 0.1, 0.1],
+    ///     ]).unwrap();
+    ///
+    /// let faces = qh.faces_as_index_arrays();
+    /// assert_eq!(faces.len(), 4);
+    /// for face in &faces {
+    ///     assert_eq!(face.len(), 3);
+    ///     assert!(!face.contains(&4));
+    /// }
+    /// ```
+    pub fn faces_as_index_arrays(&self) -> Vec<Vec<usize>> {
+        let point_index = self.point_index_lookup();
+        let mut faces = Vec::with_capacity(self.num_facets());
+        for facet in self.facets() {
+            faces.push(
+                facet
+                    .vertices()
+                    .filter_map(|v| v.point())
+                    .filter_map(point_index)
+                    .collect(),
+            );
+        }
+        faces
+    }
+
+    /// Index lookup of the coordinates of the input points
+    ///
+    /// Returns a function giving the index of a point in the input points from its coordinates,
+    /// or `None` if the coordinates do not belong to the input points.
+    pub(crate) fn point_index_lookup(&self) -> impl Fn(&[f64]) -> Option<usize> + Copy + '_ {
+        let (first_ptr, num_points) = unsafe {
+            (
+                sys::qh_get_first_point(self.qh.get()) as *const f64,
+                sys::qh_get_num_points(self.qh.get()) as usize,
+            )
+        };
+        move |point: &[f64]| {
+            let diff = (point.as_ptr() as usize).checked_sub(first_ptr as usize)?;
+            let index = diff / (std::mem::size_of::<f64>() * self.dim);
+            (index < num_points).then_some(index)
+        }
+    }
+
+    /// Dimension of the hull computed by qhull
+    ///
+    /// This is the dimension of the facet normals and of the vertex coordinates.
+    /// For Delaunay triangulations it is the dimension of the lifted points
+    /// (the dimension of the triangulated points plus one).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ]).unwrap();
+    /// assert_eq!(qh.hull_dim(), 3);
+    /// assert_eq!(qh.input_dim(), 2);
+    /// assert_eq!(qh.num_input_points(), 4);
+    /// ```
+    pub fn hull_dim(&self) -> usize {
+        unsafe { sys::qh_get_hull_dim(self.qh.get()) as _ }
+    }
+
+    /// Dimension of the input points
+    ///
+    /// This is the dimension of the points before they are lifted for a Delaunay triangulation
+    /// (when [`QhBuilder::delaunay`] is set, e.g. with [`Qh::new_delaunay`] or [`Qh::new_voronoi`]),
+    /// one less than the [hull dimension](Qh::hull_dim).
+    /// For the other hulls the two dimensions are equal.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.input_dim(), 2);
+    /// assert_eq!(qh.hull_dim(), 2);
+    /// ```
+    pub fn input_dim(&self) -> usize {
+        let qh = unsafe { &*self.qh.get() };
+        let dim = qh.input_dim as usize;
+        if qh.DELAUNAY != 0 {
+            dim.saturating_sub(1)
+        } else {
+            dim
+        }
+    }
+
+    /// The options that were passed to qhull's flag parser
+    ///
+    /// These are the raw options given with [`QhBuilder::with_flags`] and the ones
+    /// the builder methods translate to flags (e.g. `Pp` for [`QhBuilder::quiet`]),
+    /// separated by spaces and without the program name.
+    /// The settings applied directly to the qhull structure are not included.
+    ///
+    /// The string is empty if no options were passed,
+    /// it is useful to log the configuration of a failed computation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .with_flags("QbB")
+    ///     .centrum_radius(0.1)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// assert_eq!(qh.hull_option_string(), "QbB C0.1");
+    ///
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.hull_option_string(), "");
+    /// ```
+    pub fn hull_option_string(&self) -> &str {
+        &self.option_string
+    }
+
+    /// The output written by qhull to the captured stdout (`qh.fout`)
+    ///
+    /// Returns `None` if stdout is not captured (see [`QhBuilder::capture_stdout`]).
+    /// The whole output since the instance was created is returned.
+    ///
+    /// # Remarks
+    /// * the output is returned as an owned [`String`] rather than a `&str`:
+    ///   the captured stdout is a C `FILE` that has no contiguous buffer to borrow from,
+    ///   and a copy cached in the instance could not be borrowed either,
+    ///   since qhull keeps writing to the file during calls that only take `&self`
+    ///   (e.g. [`Qh::try_on_qh`]), which would have to replace the cache while it is borrowed
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .capture_stdout(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// assert_eq!(qh.last_captured_output().as_deref(), Some(""));
+    ///
+    /// // qhull's print functions write to `qh.fout`
+    /// unsafe {
+    ///     Qh::try_on_qh(&qh, |qh| sys::qh_printsummary(qh as *mut _, (*qh).fout)).unwrap();
+    /// }
+    /// assert!(qh.last_captured_output().unwrap().contains("Number of facets: 3"));
+    ///
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.last_captured_output(), None);
+    /// ```
+    pub fn last_captured_output(&self) -> Option<String> {
+        let buffers = self.buffers.borrow();
+        let output = buffers.out_file.as_ref()?.read().ok()?;
+        Some(String::from_utf8_lossy(&output).into_owned())
+    }
+
+    /// Number of input points
+    ///
+    /// This includes the points that are not vertices of the hull.
+    pub fn num_input_points(&self) -> usize {
+        unsafe { sys::qh_get_num_points(self.qh.get()) as _ }
+    }
+
+    /// Coordinates of an input point
+    ///
+    /// Returns `None` if `index` is not smaller than [`Qh::num_input_points`].
+    /// The index is the same as the one returned by [`Vertex::index`].
+    ///
+    /// # Remarks
+    /// * If qhull transformed the input (e.g. to rotate or scale it),
+    ///   these are the transformed coordinates used by qhull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// assert_eq!(qh.get_input_point(3), Some(&[0.25, 0.25][..]));
+    /// assert_eq!(qh.get_input_point(4), None);
+    /// assert_eq!(qh.iter_input_points().len(), 4);
+    ///
+    /// for vertex in qh.vertices() {
+    ///     let index = vertex.index(&qh).unwrap();
+    ///     assert_eq!(qh.get_input_point(index), vertex.coordinates());
+    /// }
+    /// ```
+    pub fn get_input_point(&self, index: usize) -> Option<&[f64]> {
+        unsafe {
+            let first_ptr = sys::qh_get_first_point(self.qh.get()) as *const f64;
+            (index < self.num_input_points() && !first_ptr.is_null())
+                .then(|| std::slice::from_raw_parts(first_ptr.add(index * self.dim), self.dim))
+        }
+    }
+
+    /// Iterate over the coordinates of the input points
+    ///
+    /// See [`Qh::get_input_point`].
+    pub fn iter_input_points(&self) -> impl ExactSizeIterator<Item = &[f64]> + DoubleEndedIterator + '_ {
+        (0..self.num_input_points()).map(|i| {
+            self.get_input_point(i)
+                .expect("index is smaller than the number of points")
+        })
     }
 
     /// Number of facets in the hull (sentinel excluded)
@@ -186,8 +1031,614 @@ impl<'a> Qh<'a> {
         unsafe { sys::qh_get_num_vertices(self.qh.get()) as _ }
     }
 
+    /// Find a facet from its [id](Facet::id)
+    ///
+    /// This is a linear scan of the facets (`O(n)`),
+    /// use [`Qh::build_face_index`] for repeated lookups.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// let facet = qh.facets().last().unwrap();
+    /// assert_eq!(qh.face_by_id(facet.id()).unwrap().id(), facet.id());
+    /// assert!(qh.face_by_id(u32::MAX).is_none());
+    /// ```
+    pub fn face_by_id(&self, id: u32) -> Option<Facet<'_>> {
+        self.facets().find(|f| f.id() == id)
+    }
+
+    /// Find a vertex from its [id](Vertex::id)
+    ///
+    /// This is a linear scan of the vertices (`O(n)`),
+    /// build a [`HashMap`] from [`Qh::vertices`] for repeated lookups.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// let vertex = qh.vertices().next().unwrap();
+    /// assert_eq!(qh.vertex_by_id(vertex.id()).unwrap().point(), vertex.point());
+    /// ```
+    pub fn vertex_by_id(&self, id: u32) -> Option<Vertex<'_>> {
+        self.vertices().find(|v| v.id() == id)
+    }
+
+    /// Map the [ids](Facet::id) of the facets to the facets
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// let index = qh.build_face_index();
+    /// assert_eq!(index.len(), qh.num_facets());
+    /// for facet in qh.facets() {
+    ///     assert_eq!(index[&facet.id()].id(), facet.id());
+    /// }
+    /// ```
+    pub fn build_face_index(&self) -> HashMap<u32, Facet<'_>> {
+        self.facets().map(|f| (f.id(), f)).collect()
+    }
+
+    /// Get all the ridges stored in the facets of the hull
+    ///
+    /// Qhull has no global list of ridges: each ridge is stored in the ridge sets of its
+    /// [top and bottom](Ridge::adjacent_faces) facets.
+    /// This function walks [`Qh::all_facets`] and yields every ridge once (from its top facet).
+    ///
+    /// # Remarks
+    /// * Qhull creates explicit ridges only for non-simplicial facets and their neighbors
+    ///   (e.g. after merging coplanar facets); the ridges between two simplicial facets
+    ///   are implicit in their [neighbors](Facet::neighbors) and are not returned,
+    ///   [`Qh::num_ridges`] counts them.
+    pub fn all_ridges(&self) -> impl Iterator<Item = Ridge<'_>> {
+        self.all_facets().flat_map(|f| f.top_ridges())
+    }
+
+    /// Get the ridges of the hull
+    ///
+    /// Ridges do not have a sentinel, but this only considers the [facets](Qh::facets)
+    /// (sentinel excluded). See [`Qh::all_ridges`] for more information.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: its 6 square faces are not simplicial
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [1.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 1.0],
+    ///         [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// assert_eq!(qh.num_facets(), 6);
+    /// assert_eq!(qh.num_ridges(), 12);
+    /// assert_eq!(qh.ridges().count(), 12);
+    /// ```
+    pub fn ridges(&self) -> impl Iterator<Item = Ridge<'_>> {
+        self.facets().flat_map(|f| f.top_ridges())
+    }
+
+    /// Number of ridges of the hull
+    ///
+    /// Each ridge is shared by two neighboring [facets](Qh::facets), so this is half the total
+    /// number of [neighbors](Facet::neighbors) of the facets, in `O(n)`.
+    /// Unlike [`Qh::ridges`], this includes the implicit ridges between simplicial facets.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a tetrahedron: all its faces are simplicial, qhull does not store its ridges
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// assert!(qh.facets().all(|f| f.simplicial()));
+    /// assert_eq!(qh.num_ridges(), 6);
+    /// assert_eq!(qh.ridges().count(), 0);
+    /// ```
+    pub fn num_ridges(&self) -> usize {
+        self.facets()
+            .map(|f| f.neighbors().map_or(0, |s| s.len()))
+            .sum::<usize>()
+            / 2
+    }
+
+    /// Volume of the hull
+    ///
+    /// Facet areas and the total volume are computed by Qhull ([`qh_getarea`](sys::qh_getarea))
+    /// the first time they are needed and reused afterwards.
+    ///
+    /// # Remarks
+    /// * an error is returned if the hull has not been [computed](Qh::compute) yet
+    /// * hulls of lower dimension than the input points have a volume of `0.0`
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [2.0, 1.0],
+    ///         [0.5, 0.5],
+    ///     ]).unwrap();
+    /// assert!((qh.volume().unwrap() - 2.0).abs() < 1e-12);
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(qh.volume().is_err());
+    /// ```
+    pub fn volume(&mut self) -> Result<f64, QhError<'_>> {
+        if !self.compute_area_volume()? {
+            return Ok(0.0);
+        }
+        Ok(unsafe { sys::qh_get_totvol(self.qh.get()) })
+    }
+
+    /// Surface area of the hull
+    ///
+    /// This is the sum of the areas of the facets, that is
+    /// * the perimeter in 2D
+    /// * the surface area in 3D
+    /// * the total `(d-1)`-dimensional measure of the facets in higher dimensions
+    ///
+    /// Like [`Qh::volume`], the values are computed only once.
+    ///
+    /// # Remarks
+    /// * an error is returned if the hull has not been [computed](Qh::compute) yet
+    /// * hulls of lower dimension than the input points have an area of `0.0`
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [2.0, 1.0],
+    ///         [0.5, 0.5],
+    ///     ]).unwrap();
+    /// assert!((qh.surface_area().unwrap() - 6.0).abs() < 1e-12);
+    /// assert!((qh.volume().unwrap() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn surface_area(&mut self) -> Result<f64, QhError<'_>> {
+        if !self.compute_area_volume()? {
+            return Ok(0.0);
+        }
+        Ok(unsafe { sys::qh_get_totarea(self.qh.get()) })
+    }
+
+    /// Centroid (center of mass) of the hull
+    ///
+    /// The hull is split into simplices joining an interior point to each facet
+    /// (or to each ridge of non-simplicial facets),
+    /// the centroid is the average of their centroids weighted by their volume.
+    ///
+    /// The returned vector has one coordinate per dimension of the hull.
+    ///
+    /// # Warning
+    /// If the hull has no volume (for example all the points are coplanar),
+    /// the average of the vertex coordinates is returned instead,
+    /// which is not the centroid of the hull in general.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [4.0, 0.0],
+    ///         [0.0, 2.0],
+    ///         [4.0, 2.0],
+    ///         [3.0, 1.0],
+    ///     ]).unwrap();
+    /// let centroid = qh.centroid().unwrap();
+    /// assert!((centroid[0] - 2.0).abs() < 1e-12);
+    /// assert!((centroid[1] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn centroid(&mut self) -> Result<Vec<f64>, QhError<'_>> {
+        let has_volume = self.compute_area_volume()?
+            && unsafe { sys::qh_get_totvol(self.qh.get()) } > 0.0;
+        let interior_point = unsafe {
+            let qh = &*self.qh.get();
+            qh.interior_point
+                .is_null()
+                .not()
+                .then(|| std::slice::from_raw_parts(qh.interior_point, self.dim))
+        };
+
+        let mut centroid = vec![0.0; self.dim];
+
+        match interior_point {
+            Some(interior_point) if has_volume => {
+                let mut total_volume = 0.0;
+                let mut add_simplex = |points: &[&[f64]]| {
+                    let volume = helpers::simplex_volume(points);
+                    total_volume += volume;
+                    for point in points {
+                        for (c, x) in centroid.iter_mut().zip(point.iter()) {
+                            *c += volume * x / points.len() as f64;
+                        }
+                    }
+                };
+
+                for facet in self.facets() {
+                    let vertices: Vec<&[f64]> = facet
+                        .vertices()
+                        .filter_map(|v| v.point())
+                        .collect();
+                    let ridges = facet.ridges().filter(|_| !facet.simplicial());
+
+                    if let Some(ridges) = ridges {
+                        // the facet is not a simplex, split it using its ridges
+                        let mut facet_center = vec![0.0; self.dim];
+                        for vertex in &vertices {
+                            for (c, x) in facet_center.iter_mut().zip(vertex.iter()) {
+                                *c += x / vertices.len() as f64;
+                            }
+                        }
+                        for ridge in ridges.iter() {
+                            let mut points = vec![interior_point, facet_center.as_slice()];
+                            points.extend(
+                                ridge.vertices().filter_map(|v| v.point()),
+                            );
+                            add_simplex(&points);
+                        }
+                    } else {
+                        let mut points = vec![interior_point];
+                        points.extend(vertices);
+                        add_simplex(&points);
+                    }
+                }
+
+                centroid.iter_mut().for_each(|c| *c /= total_volume);
+            }
+            _ => {
+                let mut count = 0;
+                for vertex in self.vertices() {
+                    if let Some(point) = vertex.point() {
+                        count += 1;
+                        for (c, x) in centroid.iter_mut().zip(point.iter()) {
+                            *c += x;
+                        }
+                    }
+                }
+                centroid.iter_mut().for_each(|c| *c /= count as f64);
+            }
+        }
+
+        Ok(centroid)
+    }
+
+    /// Whether [`Qh::compute`] has completed successfully
+    fn is_computed(&self) -> bool {
+        unsafe { (*self.qh.get()).QHULLfinished != 0 }
+    }
+
+    /// Make sure facet areas and the hull volume are available
+    ///
+    /// Returns `false` if the hull is of lower dimension than the input points,
+    /// in which case the values computed by Qhull are meaningless.
+    fn compute_area_volume(&self) -> Result<bool, QhError<'_>> {
+        if !self.is_computed() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has not been computed yet",
+            ));
+        }
+        unsafe {
+            if (sys::qh_get_hull_dim(self.qh.get()) as usize) < self.dim {
+                return Ok(false);
+            }
+            if sys::qh_get_hasAreaVolume(self.qh.get()) == 0 {
+                Qh::try_on_qh(self, |qh| {
+                    let qh = qh as *mut _;
+                    sys::qh_getarea(qh, sys::qh_get_facet_list(qh));
+                })?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// The simplicial facets of the hull
+    ///
+    /// For Delaunay triangulations, these are the simplices of the triangulation:
+    /// the lower facets of the lifted hull, or the upper facets for the
+    /// [furthest-site triangulation](Qh::new_furthest_site_delaunay) (option `Qu`),
+    /// like in qhull's output.
     pub fn simplices(&self) -> impl Iterator<Item = Facet> {
-        self.facets().filter(|f| f.simplicial())
+        let (delaunay, upper) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.DELAUNAY != 0, qh.UPPERdelaunay != 0)
+        };
+        self.facets()
+            .filter(move |f| f.simplicial() && (!delaunay || f.upper_delaunay() == upper))
+    }
+
+    /// Triangulate the non-simplicial facets of a computed hull
+    ///
+    /// The facets are split in place by [`qh_triangulate`](sys::qh_triangulate),
+    /// the new facets are [tricoplanar](Facet::tricoplanar) and share the normal of the facet
+    /// they come from. Afterwards, [`Qh::simplices`] returns all the [facets](Qh::facets).
+    /// Facet areas are computed again when they are needed.
+    ///
+    /// The triangulation cannot be undone, and calling this function again has no effect.
+    /// 2-d hulls are always simplicial and are left unchanged.
+    ///
+    /// # Errors
+    /// * If the hull has not been computed
+    /// * If qhull fails to triangulate the facets
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: its faces are squares
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// assert!((qh.surface_area().unwrap() - 6.0).abs() < 1e-12);
+    /// assert_eq!(qh.simplices().count(), 0);
+    ///
+    /// qh.triangulate_all_faces().unwrap();
+    /// assert_eq!(qh.num_facets(), 12);
+    /// assert_eq!(qh.simplices().count(), 12);
+    /// assert!((qh.surface_area().unwrap() - 6.0).abs() < 1e-12);
+    /// assert!((qh.volume().unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn triangulate_all_faces(&mut self) -> Result<(), QhError<'_>> {
+        if !self.is_computed() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has not been computed yet",
+            ));
+        }
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_triangulate(qh);
+                // the areas of the new facets are not computed
+                (*qh).hasAreaVolume = false as _;
+            })
+        }
+    }
+
+    /// Compute the facets adjacent to each vertex
+    ///
+    /// qhull only builds the [neighbor sets](Vertex::neighbors) of the vertices when it needs them
+    /// (e.g. to merge facets), this builds them for all the vertices with
+    /// [`qh_vertexneighbors`](sys::qh_vertexneighbors), so that [`Vertex::adjacent_faces`]
+    /// returns the faces containing each vertex.
+    /// [`Qh::new_voronoi`] computes them for the Voronoi cells.
+    ///
+    /// Calling this function again has no effect.
+    ///
+    /// # Errors
+    /// * If the hull has not been computed
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a tetrahedron
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// assert!(qh.vertices().all(|v| v.adjacent_faces().count() == 0));
+    ///
+    /// qh.compute_vertex_neighbors().unwrap();
+    /// assert!(qh.vertices().all(|v| v.adjacent_faces().count() == 3));
+    /// ```
+    pub fn compute_vertex_neighbors(&mut self) -> Result<(), QhError<'_>> {
+        if !self.is_computed() {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the hull has not been computed yet",
+            ));
+        }
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_vertexneighbors(qh)) }
+    }
+
+    /// Merge the adjacent facets whose normals differ by less than `angle_tolerance` radians
+    ///
+    /// qhull cannot merge the facets of a finished hull, so the hull is computed again
+    /// from the same points, with the settings it was built with (see [`Qh::reset_with_points`])
+    /// and qhull's post-merging of facets with the maximum angle `angle_tolerance` (option `An`).
+    /// This undoes [`Qh::triangulate_all_faces`], the facets are merged into convex polygons.
+    ///
+    /// Returns the decrease in the number of facets.
+    ///
+    /// # Remarks
+    /// * the indices of the input points are kept; the points added with [`Qh::add_point`]
+    ///   become input points, after the others
+    /// * all the references to facets and vertices of the previous hull are invalidated
+    ///
+    /// # Panics
+    /// * If `angle_tolerance` is not in `[0, π/2)`
+    ///
+    /// # Errors
+    /// * If qhull fails to compute the hull again
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// qh.triangulate_all_faces().unwrap();
+    /// assert_eq!(qh.num_facets(), 12);
+    ///
+    /// assert_eq!(qh.merge_coplanar_faces(1e-6).unwrap(), 6);
+    /// assert_eq!(qh.num_facets(), 6);
+    /// assert_eq!(qh.num_input_points(), 8);
+    /// ```
+    pub fn merge_coplanar_faces(&mut self, angle_tolerance: f64) -> Result<usize, QhError<'_>> {
+        assert!(
+            (0.0..std::f64::consts::FRAC_PI_2).contains(&angle_tolerance),
+            "the angle tolerance must be in [0, π/2)",
+        );
+        let num_facets = self.num_facets();
+
+        // qhull may have transformed its copy of the points, the coordinates we own are the original ones
+        let mut points = match &self.coords_holder {
+            Some(coords) => coords.clone(),
+            None => self.iter_input_points().flatten().copied().collect(),
+        };
+        points.extend(self.added_points.iter().flat_map(|p| p.iter()));
+
+        let mut builder = self.builder.take().expect("instances are created by a QhBuilder");
+        builder = builder.with_flags(&format!("A{}", angle_tolerance.cos()));
+        let result = builder.reset(self, self.dim, points);
+        builder.pop_flags();
+        self.builder = Some(builder);
+        result?;
+
+        Ok(num_facets.saturating_sub(self.num_facets()))
+    }
+
+    /// Iterate over the Delaunay simplices whose circumradius is less than `alpha`
+    ///
+    /// This is the set of simplices of the alpha shape of the input points:
+    /// small values of `alpha` keep only the small simplices,
+    /// while for a large enough `alpha` the whole Delaunay triangulation
+    /// (covering the convex hull) is returned.
+    ///
+    /// The circumradius is computed on the original coordinates of the vertices
+    /// (without the lifted coordinate), see [`helpers::circumradius`].
+    ///
+    /// # Remarks
+    /// * the hull must have been computed in Delaunay mode, see [`Qh::new_delaunay`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a square with its center, and a point far on the right
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [1.0, 1.0],
+    ///     [0.5, 0.5],
+    ///     [3.0, 0.5],
+    /// ]).unwrap();
+    ///
+    /// // the four triangles of the square have circumradius 0.5
+    /// assert_eq!(qh.alpha_shape(0.75).count(), 4);
+    /// // the triangle reaching the far point is larger
+    /// assert_eq!(qh.alpha_shape(2.0).count(), 5);
+    /// assert_eq!(qh.alpha_shape(0.25).count(), 0);
+    /// ```
+    pub fn alpha_shape(&self, alpha: f64) -> impl Iterator<Item = Facet<'_>> + '_ {
+        let dim = self.dim.saturating_sub(1);
+        self.simplices()
+            .filter(|f| !f.upper_delaunay())
+            .filter(move |f| {
+                let points = f
+                    .vertices()
+                    .filter_map(|v| v.point())
+                    .map(|p| &p[..dim])
+                    .collect::<Vec<_>>();
+                helpers::circumradius(&points) < alpha
+            })
+    }
+
+    /// Iterate over the vertices of the Voronoi diagram
+    ///
+    /// Each Voronoi vertex is the center of the circumsphere of a (lower) Delaunay facet.
+    /// The index of a vertex in this iterator is the index used by [`Qh::voronoi_edges`].
+    ///
+    /// # Remarks
+    /// * the iterator is empty if the Voronoi centers have not been computed,
+    ///   see [`Qh::new_voronoi`]
+    pub fn voronoi_vertices(&self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        self.voronoi_facets()
+            .filter_map(|f| self.voronoi_center(&f))
+            .map(|c| c.to_vec())
+    }
+
+    /// Iterate over the bounded edges of the Voronoi diagram
+    ///
+    /// Each edge is a pair of indices into [`Qh::voronoi_vertices`],
+    /// connecting the Voronoi vertices of two adjacent Delaunay facets.
+    ///
+    /// # Remarks
+    /// * the cells of the input points on the convex hull are unbounded:
+    ///   their edges going to infinity (between a Delaunay facet and the upper hull)
+    ///   are not returned
+    /// * the iterator is empty if the Voronoi centers have not been computed,
+    ///   see [`Qh::new_voronoi`]
+    pub fn voronoi_edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let index: HashMap<u32, usize> = self
+            .voronoi_facets()
+            .filter(|f| self.voronoi_center(f).is_some())
+            .enumerate()
+            .map(|(i, f)| (f.id(), i))
+            .collect();
+
+        self.voronoi_facets().flat_map(move |f| {
+            let a = index.get(&f.id()).copied();
+            f.neighbors()
+                .into_iter()
+                .flat_map(|s| s.iter())
+                .filter_map(|n| index.get(&n.id()).copied())
+                .filter_map(move |b| a.filter(|&a| a < b).map(|a| (a, b)))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Facets that are dual to a Voronoi vertex
+    fn voronoi_facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        let is_voronoi =
+            unsafe { (*self.qh.get()).CENTERtype == sys::qh_CENTER_qh_ASvoronoi };
+        self.facets()
+            .filter(move |f| is_voronoi && !f.upper_delaunay())
+    }
+
+    /// Voronoi center of a facet
+    ///
+    /// Voronoi centers have one coordinate less than the (lifted) Delaunay facets.
+    fn voronoi_center<'b>(&self, facet: &Facet<'b>) -> Option<&'b [f64]> {
+        let center = unsafe { facet.raw_ref().center };
+        center
+            .is_null()
+            .not()
+            .then(|| unsafe { std::slice::from_raw_parts(center, self.dim - 1) })
     }
 
     /// Try a function on the qhull instance
@@ -240,14 +1691,24 @@ impl<'a> Qh<'a> {
         qh: &'b Qh,
         f: impl FnOnce(*const sys::qhT) -> R,
     ) -> Result<R, QhError<'b>> {
-        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, |p| f(p)) }
+        let result = unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, |p| f(p)) };
+        #[cfg(feature = "log")]
+        unsafe {
+            qh.buffers.borrow_mut().log_messages(qh.qh.get());
+        }
+        result
     }
 
     pub unsafe fn try_on_qh_mut<'b, R>(
         qh: &'b mut Qh,
         f: impl FnOnce(*mut sys::qhT) -> R,
     ) -> Result<R, QhError<'b>> {
-        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, f) }
+        let result = unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, f) };
+        #[cfg(feature = "log")]
+        unsafe {
+            qh.buffers.borrow_mut().log_messages(qh.qh.get());
+        }
+        result
     }
 
     /// Get the pointer to the raw qhT instance
@@ -267,6 +1728,44 @@ impl<'a> Qh<'a> {
     }
 }
 
+impl<'a> std::fmt::Debug for Qh<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Qh")
+            .field("dim", &self.hull_dim())
+            .field("num_faces", &self.num_facets())
+            .field("num_vertices", &self.num_vertices())
+            .field("computed", &self.is_computed())
+            .finish()
+    }
+}
+
+/// A short summary of the hull
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let qh = Qh::builder()
+///     .build_from_iter([
+///         [0.0, 0.0],
+///         [1.0, 0.0],
+///         [0.0, 1.0],
+///         [0.25, 0.25],
+///     ]).unwrap();
+/// assert_eq!(qh.to_string(), "Convex hull in R^2 with 3 faces, 3 vertices");
+/// assert_eq!(format!("{qh:?}"), "Qh { dim: 2, num_faces: 3, num_vertices: 3, computed: true }");
+/// ```
+impl<'a> std::fmt::Display for Qh<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Convex hull in R^{} with {} faces, {} vertices",
+            self.hull_dim(),
+            self.num_facets(),
+            self.num_vertices(),
+        )
+    }
+}
+
 impl<'a> Drop for Qh<'a> {
     fn drop(&mut self) {
         unsafe {
@@ -278,14 +1777,14 @@ impl<'a> Drop for Qh<'a> {
 #[derive(Default)]
 #[allow(unused)]
 struct OwnedValues {
-    good_point_coords: Option<Rc<Vec<f64>>>,
-    good_vertex_coords: Option<Rc<Vec<f64>>>,
-    first_point: Option<Rc<Vec<f64>>>,
-    upper_threshold: Option<Rc<Vec<f64>>>,
-    lower_threshold: Option<Rc<Vec<f64>>>,
-    upper_bound: Option<Rc<Vec<f64>>>,
-    lower_bound: Option<Rc<Vec<f64>>>,
-    feasible_point: Option<Rc<Vec<f64>>>,
-    feasible_string: Option<Rc<Vec<core::ffi::c_char>>>,
-    near_zero: Option<Rc<Vec<f64>>>,
+    good_point_coords: Option<Arc<Vec<f64>>>,
+    good_vertex_coords: Option<Arc<Vec<f64>>>,
+    first_point: Option<Arc<Vec<f64>>>,
+    upper_threshold: Option<Arc<Vec<f64>>>,
+    lower_threshold: Option<Arc<Vec<f64>>>,
+    upper_bound: Option<Arc<Vec<f64>>>,
+    lower_bound: Option<Arc<Vec<f64>>>,
+    feasible_point: Option<Arc<Vec<f64>>>,
+    feasible_string: Option<Arc<Vec<core::ffi::c_char>>>,
+    near_zero: Option<Arc<Vec<f64>>>,
 }
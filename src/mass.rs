@@ -0,0 +1,379 @@
+//! Mass properties and oriented bounding boxes of 3-d hulls
+
+use crate::{Qh, QhError, QhErrorKind};
+
+/// An oriented bounding box, see [`Qh::oriented_bounding_box`]
+///
+/// The box is `{center + sum(t[i] * axes[i]) : |t[i]| <= half_extents[i]}`,
+/// the axes are a right-handed orthonormal basis.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obb {
+    pub center: Vec<f64>,
+    pub half_extents: Vec<f64>,
+    pub axes: Vec<Vec<f64>>,
+}
+
+impl Obb {
+    /// Volume of the box
+    pub fn volume(&self) -> f64 {
+        self.half_extents.iter().map(|h| 2.0 * h).product()
+    }
+
+    /// Check whether `point` is in the box, up to `tolerance` along each axis
+    ///
+    /// # Panics
+    /// * If `point` and the box do not have the same dimension
+    pub fn contains(&self, point: &[f64], tolerance: f64) -> bool {
+        assert_eq!(point.len(), self.center.len(), "point and box have different dimensions");
+        self.axes.iter().zip(&self.half_extents).all(|(axis, half)| {
+            let t: f64 = axis.iter().zip(point).zip(&self.center).map(|((a, x), c)| a * (x - c)).sum();
+            t.abs() <= half + tolerance
+        })
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// Inertia tensor of a 3-d hull of uniform `density`, about its centroid
+    ///
+    /// The hull is split in tetrahedra joining the [centroid](Qh::centroid)
+    /// to the triangles of the faces (see [`Facet::simplicial_decomposition`](crate::Facet::simplicial_decomposition)),
+    /// and the second moments of the tetrahedra are accumulated.
+    /// The tensor is expressed in the axes of the input coordinates.
+    ///
+    /// # Errors
+    /// * If the hull is not 3-d
+    /// * If the volume of the hull cannot be computed
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a box of size 1 x 2 x 3
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [1.0, 2.0, 0.0],
+    ///         [0.0, 0.0, 3.0], [1.0, 0.0, 3.0], [0.0, 2.0, 3.0], [1.0, 2.0, 3.0],
+    ///     ]).unwrap();
+    ///
+    /// let density = 0.5;
+    /// let mass = density * 6.0;
+    /// let inertia = qh.inertia_tensor(density).unwrap();
+    /// let expected = [
+    ///     mass * (4.0 + 9.0) / 12.0,
+    ///     mass * (1.0 + 9.0) / 12.0,
+    ///     mass * (1.0 + 4.0) / 12.0,
+    /// ];
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         let expected = if i == j { expected[i] } else { 0.0 };
+    ///         assert!((inertia[i][j] - expected).abs() < 1e-12);
+    ///     }
+    /// }
+    ///
+    /// // a unit cube
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// let inertia = qh.inertia_tensor(1.0).unwrap();
+    /// assert!((inertia[0][0] - 1.0 / 6.0).abs() < 1e-12);
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(qh.inertia_tensor(1.0).is_err());
+    /// ```
+    pub fn inertia_tensor(&mut self, density: f64) -> Result<[[f64; 3]; 3], QhError<'_>> {
+        if self.dim != 3 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("the inertia tensor requires a 3-d hull, got dimension {}", self.dim),
+            ));
+        }
+        let center = self.centroid().map_err(|e| e.into_static())?;
+
+        // second moments about the centroid
+        let mut moments = [[0.0; 3]; 3];
+        for facet in self.facets() {
+            let vertices: Vec<[f64; 3]> = facet
+                .vertices()
+                .filter_map(|v| v.point())
+                .map(|p| std::array::from_fn(|i| p[i] - center[i]))
+                .collect();
+            for triangle in facet.simplicial_decomposition() {
+                let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i]]);
+                // 6 times the signed volume of the tetrahedron (centroid, a, b, c)
+                let det = a[0] * (b[1] * c[2] - b[2] * c[1])
+                    - a[1] * (b[0] * c[2] - b[2] * c[0])
+                    + a[2] * (b[0] * c[1] - b[1] * c[0]);
+                let sum: [f64; 3] = std::array::from_fn(|i| a[i] + b[i] + c[i]);
+                for i in 0..3 {
+                    for j in 0..3 {
+                        let products = a[i] * a[j] + b[i] * b[j] + c[i] * c[j] + sum[i] * sum[j];
+                        moments[i][j] += density * det * products / 120.0;
+                    }
+                }
+            }
+        }
+
+        let trace = moments[0][0] + moments[1][1] + moments[2][2];
+        Ok(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { trace - moments[i][j] } else { -moments[i][j] })
+        }))
+    }
+
+    /// Principal moments of inertia and principal axes of a 3-d hull of uniform `density`
+    ///
+    /// Returns the eigenvalues of the [inertia tensor](Qh::inertia_tensor) in increasing order,
+    /// and the corresponding unit eigenvectors, which form a right-handed orthonormal basis.
+    /// The eigendecomposition uses Jacobi rotations.
+    ///
+    /// # Errors
+    /// * Same as [`Qh::inertia_tensor`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a box of size 3 x 1 x 2, rotated by 30 degrees around z
+    /// let (c, s) = (30f64.to_radians().cos(), 30f64.to_radians().sin());
+    /// let corners = (0..8).map(|i| {
+    ///     let [x, y, z] = [3.0, 1.0, 2.0].map(|l| l / 2.0);
+    ///     let [x, y, z] = [
+    ///         if i & 1 == 0 { -x } else { x },
+    ///         if i & 2 == 0 { -y } else { y },
+    ///         if i & 4 == 0 { -z } else { z },
+    ///     ];
+    ///     [c * x - s * y, s * x + c * y, z]
+    /// });
+    /// let mut qh = Qh::builder().build_from_iter(corners).unwrap();
+    ///
+    /// let (moments, axes) = qh.principal_axes(1.0).unwrap();
+    /// let mass = 6.0;
+    /// let expected = [
+    ///     mass * (1.0 + 4.0) / 12.0,
+    ///     mass * (9.0 + 1.0) / 12.0,
+    ///     mass * (9.0 + 4.0) / 12.0,
+    /// ];
+    /// for (moment, expected) in moments.iter().zip(expected) {
+    ///     assert!((moment - expected).abs() < 1e-9);
+    /// }
+    /// // the smallest moment is around the long side of the box
+    /// assert!((axes[0][0].abs() - c).abs() < 1e-9);
+    /// assert!((axes[0][1].abs() - s).abs() < 1e-9);
+    /// ```
+    pub fn principal_axes(&mut self, density: f64) -> Result<(Vec<f64>, Vec<Vec<f64>>), QhError<'_>> {
+        let tensor = self.inertia_tensor(density).map_err(|e| e.into_static())?;
+        let (values, vectors) = symmetric_eigen_3(tensor);
+        Ok((values.to_vec(), vectors.iter().map(|v| v.to_vec()).collect()))
+    }
+    /// An oriented bounding box of a 3-d hull
+    ///
+    /// The box is first aligned with the [principal axes](Qh::principal_axes) of the hull,
+    /// then each pair of axes is rotated around the third one to the rectangle of minimum area
+    /// enclosing the projection of the hull, as long as the volume decreases.
+    /// The result is usually close to the box of minimum volume, but it is not guaranteed to be minimal.
+    ///
+    /// # Errors
+    /// * Same as [`Qh::inertia_tensor`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // points on the unit sphere
+    /// let n = 2000;
+    /// let golden_angle = std::f64::consts::PI * (3.0 - 5f64.sqrt());
+    /// let points = (0..n).map(|i| {
+    ///     let z = 1.0 - 2.0 * (i as f64 + 0.5) / n as f64;
+    ///     let r = (1.0 - z * z).sqrt();
+    ///     let phi = golden_angle * i as f64;
+    ///     [r * phi.cos(), r * phi.sin(), z]
+    /// });
+    /// let mut qh = Qh::builder().build_from_iter(points).unwrap();
+    ///
+    /// // the box around the unit sphere is a cube of side 2
+    /// let obb = qh.oriented_bounding_box().unwrap();
+    /// assert!(obb.center.iter().all(|c| c.abs() < 1e-2));
+    /// assert!(obb.half_extents.iter().all(|h| (h - 1.0).abs() < 1e-2));
+    /// assert!((obb.volume() - 8.0).abs() < 0.2);
+    ///
+    /// // a rotated box is found exactly
+    /// let (c, s) = (30f64.to_radians().cos(), 30f64.to_radians().sin());
+    /// let corners = (0..8).map(|i| {
+    ///     let [x, y, z] = [
+    ///         if i & 1 == 0 { 0.0 } else { 3.0 },
+    ///         if i & 2 == 0 { 0.0 } else { 1.0 },
+    ///         if i & 4 == 0 { 0.0 } else { 2.0 },
+    ///     ];
+    ///     [c * x - s * y, s * x + c * y, z]
+    /// });
+    /// let mut qh = Qh::builder().build_from_iter(corners).unwrap();
+    /// let obb = qh.oriented_bounding_box().unwrap();
+    /// assert!((obb.volume() - 6.0).abs() < 1e-9);
+    /// assert!(qh.vertices().filter_map(|v| v.point()).all(|p| obb.contains(p, 1e-9)));
+    /// ```
+    pub fn oriented_bounding_box(&mut self) -> Result<Obb, QhError<'_>> {
+        let (_, axes) = self.principal_axes(1.0).map_err(|e| e.into_static())?;
+        let points: Vec<[f64; 3]> = self
+            .vertices()
+            .filter_map(|v| v.point())
+            .map(|p| [p[0], p[1], p[2]])
+            .collect();
+
+        let mut axes: [[f64; 3]; 3] = std::array::from_fn(|i| [axes[i][0], axes[i][1], axes[i][2]]);
+        let mut volume = box_extents(&points, &axes).iter().map(|(min, max)| max - min).product::<f64>();
+        for _ in 0..10 {
+            let mut improved = false;
+            for fixed in 0..3 {
+                let (i, j) = ((fixed + 1) % 3, (fixed + 2) % 3);
+                let (u, v) = min_area_rectangle(&points, axes[i], axes[j]);
+                let mut candidate = axes;
+                candidate[i] = u;
+                candidate[j] = v;
+                let candidate_volume = box_extents(&points, &candidate)
+                    .iter()
+                    .map(|(min, max)| max - min)
+                    .product::<f64>();
+                if candidate_volume < volume * (1.0 - 1e-12) {
+                    axes = candidate;
+                    volume = candidate_volume;
+                    improved = true;
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+
+        let extents = box_extents(&points, &axes);
+        let mut center = vec![0.0; 3];
+        for (axis, (min, max)) in axes.iter().zip(extents) {
+            for (c, a) in center.iter_mut().zip(axis) {
+                *c += a * (min + max) / 2.0;
+            }
+        }
+        Ok(Obb {
+            center,
+            half_extents: extents.iter().map(|(min, max)| (max - min) / 2.0).collect(),
+            axes: axes.iter().map(|a| a.to_vec()).collect(),
+        })
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Range of the projections of `points` on each axis
+fn box_extents(points: &[[f64; 3]], axes: &[[f64; 3]; 3]) -> [(f64, f64); 3] {
+    axes.map(|axis| {
+        points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &p| {
+            let t = dot(p, axis);
+            (min.min(t), max.max(t))
+        })
+    })
+}
+
+/// Rotation of the orthonormal pair `(u, v)` in its plane to the rectangle of minimum area
+/// enclosing the projection of `points`
+///
+/// One side of the rectangle is on an edge of the convex hull of the projection,
+/// the hull is computed with Andrew's monotone chain.
+fn min_area_rectangle(points: &[[f64; 3]], u: [f64; 3], v: [f64; 3]) -> ([f64; 3], [f64; 3]) {
+    let mut projected: Vec<[f64; 2]> = points.iter().map(|&p| [dot(p, u), dot(p, v)]).collect();
+    projected.sort_by(|p, q| p[0].total_cmp(&q[0]).then(p[1].total_cmp(&q[1])));
+    projected.dedup();
+    let turn = |o: [f64; 2], a: [f64; 2], b: [f64; 2]| {
+        (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+    };
+    let mut hull: Vec<[f64; 2]> = Vec::new();
+    for pass in [&projected[..], &projected.iter().rev().copied().collect::<Vec<_>>()[..]] {
+        let start = hull.len();
+        for &p in pass {
+            while hull.len() >= start + 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+
+    let mut best = (f64::INFINITY, [1.0, 0.0]);
+    for k in 0..hull.len() {
+        let (a, b) = (hull[k], hull[(k + 1) % hull.len()]);
+        let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+        if length == 0.0 {
+            continue;
+        }
+        let e = [(b[0] - a[0]) / length, (b[1] - a[1]) / length];
+        let (mut min_e, mut max_e, mut min_n, mut max_n) =
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY);
+        for p in &hull {
+            let (te, tn) = (p[0] * e[0] + p[1] * e[1], p[1] * e[0] - p[0] * e[1]);
+            (min_e, max_e, min_n, max_n) = (min_e.min(te), max_e.max(te), min_n.min(tn), max_n.max(tn));
+        }
+        let area = (max_e - min_e) * (max_n - min_n);
+        if area < best.0 {
+            best = (area, e);
+        }
+    }
+
+    // the rotated pair keeps the orientation of (u, v)
+    let [c, s] = best.1;
+    (
+        std::array::from_fn(|i| c * u[i] + s * v[i]),
+        std::array::from_fn(|i| c * v[i] - s * u[i]),
+    )
+}
+
+/// Eigendecomposition of a symmetric 3x3 matrix with Jacobi rotations
+///
+/// Returns the eigenvalues in increasing order and the unit eigenvectors,
+/// as a right-handed orthonormal basis.
+pub(crate) fn symmetric_eigen_3(mut m: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    // the columns of `v` are the eigenvectors
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = m[0][1].powi(2) + m[0][2].powi(2) + m[1][2].powi(2);
+        let scale = m[0][0].powi(2) + m[1][1].powi(2) + m[2][2].powi(2);
+        if off <= f64::EPSILON.powi(2) * scale {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if m[p][q] == 0.0 {
+                continue;
+            }
+            // rotation in the (p, q) plane cancelling m[p][q]
+            let theta = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let t = if theta == 0.0 { 1.0 } else { t };
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in &mut m {
+                let (mp, mq) = (row[p], row[q]);
+                row[p] = c * mp - s * mq;
+                row[q] = s * mp + c * mq;
+            }
+            let (mp, mq) = (m[p], m[q]);
+            m[p] = std::array::from_fn(|k| c * mp[k] - s * mq[k]);
+            m[q] = std::array::from_fn(|k| s * mp[k] + c * mq[k]);
+            for row in &mut v {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| m[a][a].total_cmp(&m[b][b]));
+    let values = order.map(|i| m[i][i]);
+    let mut vectors = order.map(|i| [v[0][i], v[1][i], v[2][i]]);
+    // right-handed basis
+    let [a, b, c] = vectors;
+    let cross = [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+    if cross[0] * c[0] + cross[1] * c[1] + cross[2] * c[2] < 0.0 {
+        vectors[2] = c.map(|x| -x);
+    }
+    (values, vectors)
+}
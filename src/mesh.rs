@@ -0,0 +1,262 @@
+//! Conversion of the hull to mesh representations
+
+use std::collections::HashMap;
+
+use crate::{Qh, QhBuilder, QhError, QhErrorKind};
+
+/// A triangulated 3-d hull as flat arrays
+///
+/// The layout is the one expected by most GPU APIs:
+/// * `positions`: `x, y, z` of each vertex
+/// * `normals`: `x, y, z` of the (outward) normal of each triangle
+/// * `indices`: three indices into the vertices for each triangle,
+///   in counter-clockwise order seen from outside
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    pub positions: Vec<f64>,
+    pub normals: Vec<f64>,
+    pub indices: Vec<u32>,
+}
+
+impl TriangleMesh {
+    /// Number of triangles
+    pub fn num_triangles(&self) -> usize {
+        self.indices.len() / 3
+    }
+}
+
+/// A polygonal mesh with shared vertices
+///
+/// Each face is a list of indices into `vertices`,
+/// this is the layout of the OFF and OBJ formats.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexedMesh {
+    pub vertices: Vec<[f64; 3]>,
+    pub faces: Vec<Vec<usize>>,
+}
+
+/// Vertices and faces of a hull, see [`Qh::to_topology`]
+///
+/// Each face is a list of indices into `vertices`.
+/// With the `serde` feature, the topology can be serialized to cache the result of a computation.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HullTopology {
+    pub dim: usize,
+    pub vertices: Vec<Vec<f64>>,
+    pub faces: Vec<Vec<usize>>,
+}
+
+impl<'a> Qh<'a> {
+    /// Convert a 3-d hull to a [`TriangleMesh`]
+    ///
+    /// Simplicial faces are triangles, the other faces are triangulated with a fan
+    /// from their first vertex. The normal of each triangle is the normal of its face.
+    ///
+    /// # Errors
+    /// * If the hull is not 3-d
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: 6 square faces, 12 triangles
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mesh = qh.to_triangle_mesh().unwrap();
+    /// assert_eq!(mesh.positions.len(), 8 * 3);
+    /// assert_eq!(mesh.num_triangles(), 12);
+    /// assert_eq!(mesh.normals.len(), 12 * 3);
+    ///
+    /// // the triangles are counter-clockwise seen from outside
+    /// for (t, normal) in mesh.indices.chunks(3).zip(mesh.normals.chunks(3)) {
+    ///     let p = |i: u32| &mesh.positions[i as usize * 3..i as usize * 3 + 3];
+    ///     let (a, b, c) = (p(t[0]), p(t[1]), p(t[2]));
+    ///     let e1 = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    ///     let e2 = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    ///     let cross = [
+    ///         e1[1] * e2[2] - e1[2] * e2[1],
+    ///         e1[2] * e2[0] - e1[0] * e2[2],
+    ///         e1[0] * e2[1] - e1[1] * e2[0],
+    ///     ];
+    ///     assert!(cross.iter().zip(normal).map(|(a, b)| a * b).sum::<f64>() > 0.0);
+    /// }
+    ///
+    /// // not 3-d
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(qh.to_triangle_mesh().is_err());
+    /// ```
+    pub fn to_triangle_mesh(&self) -> Result<TriangleMesh, QhError<'static>> {
+        if self.dim != 3 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("triangle meshes require a 3-d hull, got dimension {}", self.dim),
+            ));
+        }
+
+        let mut mesh = TriangleMesh::default();
+        let mut index = HashMap::new();
+        for vertex in self.vertices() {
+            let Some(point) = vertex.point() else { continue };
+            index.insert(vertex.id(), (mesh.positions.len() / 3) as u32);
+            mesh.positions.extend_from_slice(point);
+        }
+
+        for facet in self.facets() {
+            let (Some(normal), Some(vertices)) = (facet.normal(), facet.ordered_vertices_3d()) else {
+                continue;
+            };
+            let indices = vertices
+                .iter()
+                .filter_map(|(v, _)| index.get(&v.id()).copied())
+                .collect::<Vec<_>>();
+            for pair in indices.get(1..).unwrap_or_default().windows(2) {
+                mesh.indices.extend_from_slice(&[indices[0], pair[0], pair[1]]);
+                mesh.normals.extend_from_slice(normal);
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    /// Convert the hull to an [`IndexedMesh`]
+    ///
+    /// The faces are not triangulated: for a 3-d hull each face is a polygon,
+    /// with its vertices in counter-clockwise order seen from outside.
+    /// For a 2-d hull the faces are the edges of the polygon and the `z` coordinates are `0`.
+    ///
+    /// The vertices are deduplicated by [id](crate::Vertex::id),
+    /// they appear in `vertices` in the order they are first used by the faces.
+    ///
+    /// # Panics
+    /// * If the hull has more than 3 dimensions
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a square pyramid
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.5, 0.5, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mesh = qh.to_indexed_mesh();
+    /// assert_eq!(mesh.vertices.len(), 5);
+    /// assert_eq!(mesh.faces.len(), 5);
+    /// let mut sizes = mesh.faces.iter().map(|f| f.len()).collect::<Vec<_>>();
+    /// sizes.sort();
+    /// assert_eq!(sizes, vec![3, 3, 3, 3, 4]);
+    /// ```
+    pub fn to_indexed_mesh(&self) -> IndexedMesh {
+        assert!(self.dim <= 3, "indexed meshes require a hull of at most 3 dimensions");
+
+        let (vertices, faces) = self.indexed_faces();
+        let vertices = vertices
+            .into_iter()
+            .map(|point| std::array::from_fn(|i| point.get(i).copied().unwrap_or(0.0)))
+            .collect();
+        IndexedMesh { vertices, faces }
+    }
+
+    /// Vertices and faces of the hull, with the faces indexing into the vertices
+    ///
+    /// Like [`Qh::to_indexed_mesh`] but for any dimension:
+    /// the faces of a 3-d hull are ordered polygons,
+    /// in other dimensions the vertices of a face are in qhull's order.
+    pub(crate) fn indexed_faces(&self) -> (Vec<&[f64]>, Vec<Vec<usize>>) {
+        let mut vertices = Vec::new();
+        let mut index = HashMap::new();
+        let faces = self
+            .facets()
+            .filter_map(|facet| {
+                let face_vertices = if self.dim == 3 {
+                    facet.ordered_vertices_3d()?
+                } else {
+                    facet
+                        .vertices()
+                        .filter_map(|v| Some((v, v.point()?)))
+                        .collect()
+                };
+                let face = face_vertices
+                    .into_iter()
+                    .map(|(vertex, point)| {
+                        *index.entry(vertex.id()).or_insert_with(|| {
+                            vertices.push(point);
+                            vertices.len() - 1
+                        })
+                    })
+                    .collect();
+                Some(face)
+            })
+            .collect();
+        (vertices, faces)
+    }
+
+    /// Extract the vertices and faces of the hull
+    ///
+    /// The faces are the same as in [`Qh::to_indexed_mesh`], but the hull can have any dimension.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let topology = qh.to_topology();
+    /// assert_eq!(topology.dim, 2);
+    /// assert_eq!(topology.vertices.len(), 3);
+    /// assert_eq!(topology.faces.len(), 3);
+    ///
+    /// # #[cfg(feature = "serde")] {
+    /// let json = serde_json::to_string(&topology).unwrap();
+    /// let cached: HullTopology = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(cached, topology);
+    /// # }
+    ///
+    /// let qh = Qh::from_topology(&topology).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    pub fn to_topology(&self) -> HullTopology {
+        let (vertices, faces) = self.indexed_faces();
+        HullTopology {
+            dim: self.dim,
+            vertices: vertices.into_iter().map(|v| v.to_vec()).collect(),
+            faces,
+        }
+    }
+
+    /// Rebuild a hull from the vertices of a [`HullTopology`]
+    ///
+    /// The hull is computed again from the vertices (the faces are not used),
+    /// which is cheap since all the points are extreme.
+    ///
+    /// # Errors
+    /// * If a vertex does not have `dim` coordinates
+    /// * If qhull fails to compute the hull
+    pub fn from_topology(topo: &HullTopology) -> Result<Qh<'static>, QhError<'static>> {
+        if let Some(vertex) = topo.vertices.iter().find(|v| v.len() != topo.dim) {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("vertex {vertex:?} does not have {} coordinates", topo.dim),
+            ));
+        }
+        QhBuilder::default().build_from_flat_slice(topo.dim, topo.vertices.concat())
+    }
+}
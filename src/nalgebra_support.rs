@@ -0,0 +1,68 @@
+//! Integration with the [`nalgebra`] crate
+
+use crate::{Facet, Qh, QhBuilder, QhError, Vertex};
+
+impl QhBuilder {
+    /// Build a Qhull instance from [`nalgebra`] points
+    ///
+    /// The dimension of the hull is the dimension `D` of the points.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use nalgebra::Point2;
+    ///
+    /// let qh = QhBuilder::default()
+    ///     .build_from_points_nalgebra([
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(1.0, 0.0),
+    ///         Point2::new(0.0, 1.0),
+    ///         Point2::new(0.25, 0.25),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// for vertex in qh.vertices() {
+    ///     let point: Point2<f64> = vertex.as_point().unwrap();
+    ///     assert!(point.coords.sum() <= 1.0);
+    /// }
+    /// for facet in qh.facets() {
+    ///     let normal = facet.normal_as_vector::<2>().unwrap();
+    ///     assert!((normal.norm() - 1.0).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn build_from_points_nalgebra<const D: usize>(
+        self,
+        points: impl IntoIterator<Item = nalgebra::Point<f64, D>>,
+    ) -> Result<Qh<'static>, QhError<'static>> {
+        let coords = points
+            .into_iter()
+            .flat_map(|p| p.coords.into_iter().copied().collect::<Vec<_>>())
+            .collect();
+        self.build_from_flat_slice(D, coords)
+    }
+}
+
+impl<'a> Facet<'a> {
+    /// Normal of the facet as a [`nalgebra`] vector
+    ///
+    /// Returns `None` if the facet has no normal (see [`Facet::normal`])
+    /// or if `D` is not the dimension of the facet.
+    pub fn normal_as_vector<const D: usize>(&self) -> Option<nalgebra::SVector<f64, D>> {
+        self.normal()
+            .filter(|n| n.len() == D)
+            .map(nalgebra::SVector::from_column_slice)
+    }
+}
+
+impl<'a> Vertex<'a> {
+    /// Coordinates of the vertex as a [`nalgebra`] point
+    ///
+    /// Returns `None` if the vertex has no coordinates (see [`Vertex::coordinates`])
+    /// or if `D` is not the dimension of the vertex.
+    pub fn as_point<const D: usize>(&self) -> Option<nalgebra::Point<f64, D>> {
+        self.coordinates()
+            .filter(|p| p.len() == D)
+            .map(nalgebra::Point::from_slice)
+    }
+}
@@ -0,0 +1,60 @@
+//! Integration with the [`ndarray`] crate
+
+use ndarray::Array2;
+
+use crate::{Qh, QhBuilder, QhError};
+
+impl QhBuilder {
+    /// Build a Qhull instance from an array of shape `(n_points, dim)`
+    ///
+    /// The coordinates are copied into a row-major buffer owned by the instance:
+    /// C-contiguous arrays are copied as they are, other layouts
+    /// (e.g. Fortran order or sliced views) are converted.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use ndarray::array;
+    ///
+    /// let points = array![
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ];
+    /// let qh = QhBuilder::default().build_from_array2(&points).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// // the same points in column-major order
+    /// let transposed = points.t().to_owned();
+    /// let qh = QhBuilder::default().build_from_array2(&transposed.reversed_axes()).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let vertices = qh.vertex_coordinates_array2();
+    /// assert_eq!(vertices.dim(), (3, 2));
+    /// assert!(vertices.rows().into_iter().all(|p| p.sum() <= 1.0));
+    /// ```
+    pub fn build_from_array2(self, arr: &Array2<f64>) -> Result<Qh<'static>, QhError<'static>> {
+        let coords = match arr.as_slice() {
+            Some(slice) => slice.to_vec(),
+            None => arr.iter().copied().collect(),
+        };
+        self.build_from_flat_slice(arr.ncols(), coords)
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// Coordinates of the vertices as an array of shape `(num_vertices, dim)`
+    ///
+    /// The rows are in the same order as [`Qh::vertices`].
+    pub fn vertex_coordinates_array2(&self) -> Array2<f64> {
+        let coords: Vec<f64> = self
+            .vertices()
+            .filter_map(|v| v.coordinates())
+            .flatten()
+            .copied()
+            .collect();
+        Array2::from_shape_vec((coords.len() / self.dim, self.dim), coords)
+            .expect("every vertex has dim coordinates")
+    }
+}
@@ -0,0 +1,225 @@
+//! Queries and operations on pairs of hulls
+
+use crate::{helpers, Qh, QhBuilder, QhError, QhErrorKind};
+
+impl<'a> Qh<'a> {
+    /// The Hausdorff distance between two hulls
+    ///
+    /// This is the largest distance from a point of one hull to the other hull, in both directions.
+    /// The distance to a convex set is a convex function, so the largest distance is reached
+    /// at a vertex: each vertex of `a` is [projected](Qh::project_point_onto_hull) on `b`
+    /// (or is at distance `0.0` if it is inside `b`) and vice versa,
+    /// in `O(|vertices of a| * |faces of b| + |vertices of b| * |faces of a|)`.
+    ///
+    /// Note that the Hausdorff distance of disjoint hulls is not the gap between them,
+    /// which is the smallest distance between their points (see [`Qh::separation_distance`]).
+    ///
+    /// # Panics
+    /// * If the hulls do not have the same dimension
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let cube = |x: f64| {
+    ///     Qh::builder()
+    ///         .build_from_iter((0..8).map(|i| [
+    ///             x + (i & 1) as f64,
+    ///             ((i >> 1) & 1) as f64,
+    ///             ((i >> 2) & 1) as f64,
+    ///         ]))
+    ///         .unwrap()
+    /// };
+    /// let a = cube(0.0);
+    ///
+    /// // two unit cubes with a gap of 2 between them:
+    /// // the far face of each cube is at distance 2 + 1 from the other one
+    /// let b = cube(3.0);
+    /// assert!((Qh::hausdorff_distance(&a, &b) - 3.0).abs() < 1e-12);
+    ///
+    /// assert_eq!(Qh::hausdorff_distance(&a, &a), 0.0);
+    ///
+    /// // a tetrahedron in the corner of the cube: the corner (1, 1, 1) is the farthest point
+    /// let tetrahedron = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    ///     .unwrap();
+    /// let expected = 2.0 / 3f64.sqrt(); // distance from (1, 1, 1) to the plane x + y + z = 1
+    /// assert!((Qh::hausdorff_distance(&a, &tetrahedron) - expected).abs() < 1e-12);
+    /// ```
+    pub fn hausdorff_distance(a: &Qh, b: &Qh) -> f64 {
+        assert_eq!(a.dim, b.dim, "the hulls have different dimensions");
+        directed_hausdorff_distance(a, b).max(directed_hausdorff_distance(b, a))
+    }
+
+    /// Check whether two hulls intersect
+    ///
+    /// This is the GJK algorithm (Gilbert–Johnson–Keerthi): the hulls intersect if the origin
+    /// is in their Minkowski difference `a - b`, whose support point in a direction `d` is
+    /// the [extreme point](Qh::extreme_point) of `a` in `d` minus the extreme point of `b` in `-d`.
+    /// The closest point of the difference to the origin is approached with these support points,
+    /// the closest point of their convex hull is found with
+    /// [`helpers::closest_point_in_convex_hull`].
+    /// Touching hulls intersect (up to rounding errors).
+    /// Returns `false` if one of the hulls has no vertices.
+    ///
+    /// # Panics
+    /// * If the hulls do not have the same dimension
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let square = |x: f64, y: f64| {
+    ///     Qh::builder()
+    ///         .build_from_iter([[x, y], [x + 1.0, y], [x, y + 1.0], [x + 1.0, y + 1.0]])
+    ///         .unwrap()
+    /// };
+    /// let a = square(0.0, 0.0);
+    /// assert!(Qh::intersection_test(&a, &square(0.5, 0.5)));
+    /// assert!(Qh::intersection_test(&a, &square(1.0, 0.0)));
+    /// assert!(!Qh::intersection_test(&a, &square(1.5, 0.5)));
+    ///
+    /// // a triangle crossing the square, without vertices inside the square
+    /// let triangle = Qh::builder()
+    ///     .build_from_iter([[-1.0, 0.5], [2.0, 0.4], [2.0, 0.6]])
+    ///     .unwrap();
+    /// assert!(Qh::intersection_test(&a, &triangle));
+    /// ```
+    pub fn intersection_test(a: &Qh, b: &Qh) -> bool {
+        assert_eq!(a.dim, b.dim, "the hulls have different dimensions");
+        matches!(gjk_distance(a, b), Some(distance) if distance == 0.0)
+    }
+
+    /// The distance between two disjoint hulls (the gap between them)
+    ///
+    /// This is the smallest distance between a point of `a` and a point of `b`,
+    /// which is not necessarily reached at vertices (e.g. between two edges in 3-d).
+    /// It is computed with the same GJK iterations as [`Qh::intersection_test`],
+    /// which converge to the closest points of the two hulls.
+    /// Returns `None` if the hulls intersect or if one of them has no vertices.
+    ///
+    /// # Panics
+    /// * If the hulls do not have the same dimension
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let square = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// let triangle = Qh::builder()
+    ///     .build_from_iter([[2.0, 2.0], [3.0, 2.0], [2.0, 3.0]])
+    ///     .unwrap();
+    /// let distance = Qh::separation_distance(&square, &triangle).unwrap();
+    /// assert!((distance - 2f64.sqrt()).abs() < 1e-12);
+    ///
+    /// let overlapping = Qh::builder()
+    ///     .build_from_iter([[0.5, 0.5], [3.0, 0.5], [0.5, 3.0]])
+    ///     .unwrap();
+    /// assert_eq!(Qh::separation_distance(&square, &overlapping), None);
+    ///
+    /// // between two edges in 3-d, the closest vertices are further apart
+    /// let a = Qh::builder()
+    ///     .build_from_iter([[-1.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, -0.1, -1.0], [0.0, 0.1, -1.0]])
+    ///     .unwrap();
+    /// let b = Qh::builder()
+    ///     .build_from_iter([[0.0, -1.0, 1.0], [0.0, 1.0, 1.0], [-0.1, 0.0, 2.0], [0.1, 0.0, 2.0]])
+    ///     .unwrap();
+    /// let distance = Qh::separation_distance(&a, &b).unwrap();
+    /// assert!((distance - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn separation_distance(a: &Qh, b: &Qh) -> Option<f64> {
+        assert_eq!(a.dim, b.dim, "the hulls have different dimensions");
+        gjk_distance(a, b).filter(|&distance| distance > 0.0)
+    }
+
+    /// The convex hull of the union of two hulls
+    ///
+    /// Only the vertices of `a` and `b` are collected, since the other points of either hull
+    /// cannot be on the boundary of the union hull, and the hull of these vertices is computed
+    /// with the default settings.
+    /// This is cheaper than computing the hull of all the input points again.
+    ///
+    /// # Errors
+    /// * If the hulls do not have the same dimension
+    /// * If qhull fails to compute the hull of the vertices
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let a = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]])
+    ///     .unwrap();
+    /// let b = Qh::builder()
+    ///     .build_from_iter([[2.0, 0.0], [3.0, 0.0], [2.0, 1.0], [3.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// // a 3 x 1 rectangle
+    /// let mut union = Qh::convex_hull_of_union(&a, &b).unwrap();
+    /// assert_eq!(union.num_vertices(), 4);
+    /// assert!((union.volume().unwrap() - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn convex_hull_of_union(a: &Qh, b: &Qh) -> Result<Qh<'static>, QhError<'static>> {
+        if a.dim != b.dim {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("the hulls have different dimensions, {} and {}", a.dim, b.dim),
+            ));
+        }
+        let coords: Vec<f64> = a
+            .vertices()
+            .chain(b.vertices())
+            .filter_map(|v| v.point())
+            .flatten()
+            .copied()
+            .collect();
+        QhBuilder::default().build_from_flat_slice(a.dim, coords)
+    }
+}
+
+/// Distance between two hulls with the GJK algorithm, see [`Qh::intersection_test`]
+///
+/// Returns `0.0` if the hulls intersect, `None` if one of them has no vertices.
+fn gjk_distance(a: &Qh, b: &Qh) -> Option<f64> {
+    let support = |direction: &[f64]| -> Option<Vec<f64>> {
+        let opposite: Vec<f64> = direction.iter().map(|x| -x).collect();
+        let p = a.extreme_point(direction)?.point()?;
+        let q = b.extreme_point(&opposite)?.point()?;
+        Some(p.iter().zip(q).map(|(p, q)| p - q).collect())
+    };
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+    let mut v = support(&vec![1.0; a.dim])?;
+    let mut points = vec![v.clone()];
+    let mut scale = dot(&v, &v);
+    for _ in 0..100 * (a.dim + 1) {
+        let vv = dot(&v, &v);
+        if vv <= 1e-24 * scale {
+            return Some(0.0);
+        }
+        let opposite: Vec<f64> = v.iter().map(|x| -x).collect();
+        let w = support(&opposite)?;
+        // `v · w` is a lower bound of `|v| * distance`
+        if vv - dot(&v, &w) <= 1e-12 * vv || points.contains(&w) {
+            return Some(vv.sqrt());
+        }
+        scale = scale.max(dot(&w, &w));
+        points.push(w);
+        let simplex: Vec<&[f64]> = points.iter().map(|p| p.as_slice()).collect();
+        v = helpers::closest_point_in_convex_hull(&simplex, &vec![0.0; a.dim]);
+    }
+    Some(dot(&v, &v).sqrt())
+}
+
+/// Largest distance from a vertex of `from` to the hull `to`
+fn directed_hausdorff_distance(from: &Qh, to: &Qh) -> f64 {
+    from.vertices()
+        .filter_map(|v| v.point())
+        .map(|point| {
+            if to.distance_to_hull(point) <= 0.0 {
+                return 0.0;
+            }
+            let closest = to.project_point_onto_hull(point);
+            closest.iter().zip(point).map(|(c, x)| (c - x).powi(2)).sum::<f64>().sqrt()
+        })
+        .fold(0.0, f64::max)
+}
@@ -0,0 +1,473 @@
+//! Geometric queries on the hull
+
+use crate::{helpers, sys, Facet, Qh, QhError, QhErrorKind, Vertex};
+
+/// Status of an input point with respect to the hull, see [`Qh::classify_points`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointStatus {
+    /// The point is a vertex of the hull
+    OnHull,
+    /// The point is strictly inside the hull
+    Interior,
+    /// The point is on a facet of the hull (within the coplanar tolerance) but is not a vertex
+    Coplanar,
+    /// The point is outside the hull (only when the hull is not complete)
+    Outside,
+}
+
+impl<'a> Qh<'a> {
+    /// Test whether a point lies inside the convex hull
+    ///
+    /// The point is inside if it is not above any facet hyperplane
+    /// (see [`Facet::signed_distance_to_point`](crate::Facet::signed_distance_to_point)).
+    /// Points on the boundary might be classified either way because of rounding errors,
+    /// use [`Qh::is_point_inside_with_tol`] to include them.
+    ///
+    /// # Errors
+    /// * If `point.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert!(qh.is_point_inside(&[0.5, 0.5]).unwrap());
+    /// assert!(!qh.is_point_inside(&[1.5, 0.5]).unwrap());
+    /// assert!(qh.is_point_inside(&[0.5, 0.5, 0.5]).is_err());
+    ///
+    /// assert!(qh.is_point_inside_with_tol(&[1.0 + 1e-12, 0.5], 1e-9));
+    /// assert!(!qh.is_point_inside_with_tol(&[1.0 + 1e-6, 0.5], 1e-9));
+    /// ```
+    pub fn is_point_inside(&self, point: &[f64]) -> Result<bool, QhError<'static>> {
+        self.check_query_point(point)?;
+        Ok(self.is_point_inside_with_tol(point, 0.0))
+    }
+
+    /// Test whether a point lies inside the convex hull, up to a tolerance
+    ///
+    /// The point is inside if its distance above every facet is at most `tol`,
+    /// so that a positive `tol` includes the points on the boundary.
+    ///
+    /// # Panics
+    /// * If `point.len()` is not the dimension of the hull
+    pub fn is_point_inside_with_tol(&self, point: &[f64], tol: f64) -> bool {
+        assert_eq!(point.len(), self.dim, "point has the wrong dimension");
+        self.facets()
+            .filter_map(|f| f.signed_distance_to_point(point))
+            .all(|d| d <= tol)
+    }
+
+    /// Signed distance from a point to the boundary of the hull
+    ///
+    /// This is the largest [signed distance](crate::Facet::signed_distance_to_point)
+    /// of the point to the facet hyperplanes:
+    /// for points inside the hull it is negative (or `0.0` on the boundary)
+    /// and its absolute value is the exact distance to the boundary.
+    ///
+    /// For points outside the hull it is the distance to the nearest hyperplane,
+    /// which is exact when the projection of the point falls on a facet,
+    /// but is only a lower bound of the true distance when the nearest point of the hull
+    /// is on a ridge or on a vertex.
+    ///
+    /// # Panics
+    /// * If `point.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert!((qh.distance_to_hull(&[0.5, 0.25]) + 0.25).abs() < 1e-12);
+    /// assert!((qh.distance_to_hull(&[3.0, 0.5]) - 2.0).abs() < 1e-12);
+    ///
+    /// // the nearest point is the corner (1, 1), at distance sqrt(2)
+    /// let d = qh.distance_to_hull(&[2.0, 2.0]);
+    /// assert!((d - 1.0).abs() < 1e-12 && d < 2.0f64.sqrt());
+    /// ```
+    pub fn distance_to_hull(&self, point: &[f64]) -> f64 {
+        assert_eq!(point.len(), self.dim, "point has the wrong dimension");
+        self.facets()
+            .filter_map(|f| f.signed_distance_to_point(point))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// The facet whose hyperplane is nearest to a point
+    ///
+    /// Returns the facet minimizing the absolute value of
+    /// [`Facet::signed_distance_to_point`], or `None` if no facet has a hyperplane.
+    ///
+    /// For points inside the hull (or close to its boundary) this is the facet
+    /// that "almost contains" the point.
+    /// For points far outside, this is the nearest supporting hyperplane,
+    /// which is not necessarily the closest facet of the hull:
+    /// the hyperplane might pass close to the point far away from the facet itself.
+    ///
+    /// # Panics
+    /// * If `point.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let facet = qh.nearest_face(&[0.9, 0.5]).unwrap();
+    /// assert!((facet.normal().unwrap()[0] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn nearest_face(&self, point: &[f64]) -> Option<Facet<'_>> {
+        assert_eq!(point.len(), self.dim, "point has the wrong dimension");
+        self.facets()
+            .filter_map(|f| Some((f, f.signed_distance_to_point(point)?.abs())))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(f, _)| f)
+    }
+
+    /// The vertex furthest in a given direction (support point)
+    ///
+    /// Returns the vertex maximizing `coordinates · direction`,
+    /// or `None` if the hull has no vertices.
+    /// This is a linear scan over the vertices.
+    ///
+    /// # Panics
+    /// * If `direction.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [2.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let vertex = qh.extreme_point(&[1.0, 0.0]).unwrap();
+    /// assert_eq!(vertex.coordinates(), Some(&[2.0, 0.0][..]));
+    /// let vertex = qh.extreme_point(&[-1.0, 1.0]).unwrap();
+    /// assert_eq!(vertex.coordinates(), Some(&[0.0, 1.0][..]));
+    /// ```
+    pub fn extreme_point(&self, direction: &[f64]) -> Option<Vertex<'_>> {
+        assert_eq!(direction.len(), self.dim, "direction has the wrong dimension");
+        self.vertices()
+            .filter_map(|v| Some((v, dot(v.coordinates()?, direction))))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(v, _)| v)
+    }
+
+    /// The facet hyperplane that best supports the hull in a direction
+    ///
+    /// Returns the `(normal, offset)` of the facet whose outward normal is best aligned
+    /// with `direction` (the facet maximizing `normal · direction`),
+    /// or `None` if no facet has a hyperplane.
+    /// The hyperplane is `normal · x + offset = 0` and the hull is on its negative side.
+    /// If several facets are equally aligned any one of them is returned.
+    ///
+    /// The hyperplane touches the hull on the whole facet;
+    /// use [`Qh::extreme_point`] to find a point of the hull furthest along `direction`.
+    ///
+    /// # Panics
+    /// * If `direction.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let (normal, offset) = qh.supporting_hyperplane(&[0.2, 1.0]).unwrap();
+    /// assert!(normal[0].abs() < 1e-12 && (normal[1] - 1.0).abs() < 1e-12);
+    /// assert!((offset + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn supporting_hyperplane(&self, direction: &[f64]) -> Option<(Vec<f64>, f64)> {
+        assert_eq!(direction.len(), self.dim, "direction has the wrong dimension");
+        self.facets()
+            .filter_map(|f| Some((f.normal()?, f.offset()?)))
+            .max_by(|(a, _), (b, _)| dot(a, direction).total_cmp(&dot(b, direction)))
+            .map(|(normal, offset)| (normal.to_vec(), offset))
+    }
+
+    /// The closest point on the boundary of the hull
+    ///
+    /// For points outside the hull this is the closest point of the hull,
+    /// for points inside it is the nearest point on one of the facets.
+    /// Each facet is handled as the convex hull of its vertices
+    /// (see [`helpers::closest_point_in_convex_hull`]), so the result is exact
+    /// for non-simplicial facets too.
+    ///
+    /// # Panics
+    /// * If `point.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12);
+    /// assert!(close(&qh.project_point_onto_hull(&[0.5, 0.2]), &[0.5, 0.0]));
+    /// assert!(close(&qh.project_point_onto_hull(&[3.0, 0.5]), &[1.0, 0.5]));
+    /// assert!(close(&qh.project_point_onto_hull(&[2.0, 2.0]), &[1.0, 1.0]));
+    /// ```
+    pub fn project_point_onto_hull(&self, point: &[f64]) -> Vec<f64> {
+        assert_eq!(point.len(), self.dim, "point has the wrong dimension");
+        let squared_distance = |p: &[f64]| p.iter().zip(point).map(|(a, b)| (a - b).powi(2)).sum::<f64>();
+        self.facets()
+            .map(|f| {
+                let vertices: Vec<&[f64]> = f.vertices().filter_map(|v| v.coordinates()).collect();
+                helpers::closest_point_in_convex_hull(&vertices, point)
+            })
+            .min_by(|a, b| squared_distance(a).total_cmp(&squared_distance(b)))
+            .expect("the hull has facets")
+    }
+
+    /// Intersection of a ray with the hull
+    ///
+    /// The ray is `origin + t * direction` with `t >= 0`.
+    /// Returns the parameter `t` of the point where the ray enters the hull
+    /// and the facet it enters through, or `None` if the ray misses the hull
+    /// (or the hull is behind the origin).
+    ///
+    /// The intersection is computed with the slab method on the facet half-spaces.
+    /// If the origin is inside the hull, the entry point is behind the origin and `t` is negative.
+    ///
+    /// # Panics
+    /// * If `origin.len()` or `direction.len()` is not the dimension of the hull
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let (t, facet) = qh.ray_intersection(&[-1.0, 0.5], &[2.0, 0.0]).unwrap();
+    /// assert!((t - 0.5).abs() < 1e-12);
+    /// assert!((facet.normal().unwrap()[0] + 1.0).abs() < 1e-12);
+    ///
+    /// // missing the hull
+    /// assert!(qh.ray_intersection(&[-1.0, 0.5], &[0.0, 1.0]).is_none());
+    /// // pointing away from the hull
+    /// assert!(qh.ray_intersection(&[-1.0, 0.5], &[-1.0, 0.0]).is_none());
+    /// ```
+    pub fn ray_intersection(&self, origin: &[f64], direction: &[f64]) -> Option<(f64, Facet<'_>)> {
+        assert_eq!(origin.len(), self.dim, "origin has the wrong dimension");
+        assert_eq!(direction.len(), self.dim, "direction has the wrong dimension");
+
+        let mut enter: Option<(f64, Facet)> = None;
+        let mut t_exit = f64::INFINITY;
+        for facet in self.facets() {
+            let (Some(distance), Some(normal)) = (facet.signed_distance_to_point(origin), facet.normal()) else {
+                continue;
+            };
+            let speed = dot(normal, direction);
+            if speed == 0.0 {
+                // parallel to the facet
+                if distance > 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let t = -distance / speed;
+            if speed < 0.0 {
+                if enter.is_none_or(|(t_enter, _)| t > t_enter) {
+                    enter = Some((t, facet));
+                }
+            } else {
+                t_exit = t_exit.min(t);
+            }
+        }
+
+        enter.filter(|&(t_enter, _)| t_enter <= t_exit && t_exit >= 0.0)
+    }
+
+    /// Classify the input points with respect to the hull
+    ///
+    /// Returns the status of each input point by reading the assignments made by qhull:
+    /// the vertices are [`OnHull`](PointStatus::OnHull), the points in the outside sets
+    /// of the facets are [`Outside`](PointStatus::Outside) and the points in the coplanar sets are
+    /// [`Coplanar`](PointStatus::Coplanar) (or [`Interior`](PointStatus::Interior)
+    /// if they are further below the facet than the coplanar tolerance).
+    /// The remaining points are [`Interior`](PointStatus::Interior).
+    ///
+    /// # Remarks
+    /// * qhull only keeps the coplanar points with the `Qc` option
+    ///   (see [`QhBuilder::keep_coplanar`](crate::QhBuilder::keep_coplanar)),
+    ///   otherwise they are reported as interior
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .keep_coplanar(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///         [0.5, 0.5],
+    ///         [0.5, 0.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// use PointStatus::*;
+    /// assert_eq!(
+    ///     qh.classify_points(),
+    ///     vec![OnHull, OnHull, OnHull, OnHull, Interior, Coplanar],
+    /// );
+    /// ```
+    pub fn classify_points(&self) -> Vec<PointStatus> {
+        let num_points = unsafe { sys::qh_get_num_points(self.qh.get()) as usize };
+        let max_coplanar = unsafe { (*self.qh.get()).MAXcoplanar };
+        let point_index = self.point_index_lookup();
+        let mut status = vec![PointStatus::Interior; num_points];
+
+        for facet in self.facets() {
+            for index in facet.outside_points().filter_map(point_index) {
+                status[index] = PointStatus::Outside;
+            }
+            for point in facet.coplanar_points() {
+                let coplanar = facet
+                    .signed_distance_to_point(point)
+                    .is_some_and(|d| d >= -max_coplanar);
+                if let (Some(index), true) = (point_index(point), coplanar) {
+                    status[index] = PointStatus::Coplanar;
+                }
+            }
+        }
+        for index in self.vertices().filter_map(|v| v.point()).filter_map(point_index) {
+            status[index] = PointStatus::OnHull;
+        }
+        status
+    }
+
+    /// Iterate over the facets together with the indices of their coplanar points
+    ///
+    /// qhull assigns each coplanar point (see [`PointStatus::Coplanar`]) to its nearest facet.
+    /// Only the facets with at least one coplanar point are returned.
+    ///
+    /// # Remarks
+    /// * qhull only keeps the coplanar points with the `Qc` option
+    ///   (see [`QhBuilder::keep_coplanar`](crate::QhBuilder::keep_coplanar)),
+    ///   and also keeps the inside points with `Qi`
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .keep_coplanar(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///         [0.5, 0.0],
+    ///         [0.25, 0.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let coplanar = qh.face_coplanar_points().collect::<Vec<_>>();
+    /// assert_eq!(coplanar.len(), 1);
+    /// let (facet, mut points) = coplanar.into_iter().next().unwrap();
+    /// points.sort();
+    /// assert_eq!(points, vec![4, 5]);
+    /// assert!(facet.normal().unwrap()[1] < -0.5);
+    /// ```
+    pub fn face_coplanar_points(&self) -> impl Iterator<Item = (Facet<'_>, Vec<usize>)> + '_ {
+        let point_index = self.point_index_lookup();
+        self.facets()
+            .map(move |f| (f, f.coplanar_points().filter_map(point_index).collect::<Vec<_>>()))
+            .filter(|(_, points)| !points.is_empty())
+    }
+
+    /// Iterate over the facets together with the indices of the points outside them
+    ///
+    /// These are the points that qhull has not processed yet, so the sets are empty
+    /// once the hull is complete. They are only non-empty if qhull was stopped early
+    /// (e.g. with the `TA` option, see [`QhBuilder::with_flags`](crate::QhBuilder::with_flags)).
+    /// The last point of each list is the furthest from the facet.
+    /// Note that the point qhull was about to add when it stopped is not in any outside set.
+    /// Only the facets with at least one outside point are returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a regular heptagon
+    /// let points: Vec<[f64; 2]> = (0..7)
+    ///     .map(|i| i as f64 * std::f64::consts::TAU / 7.0)
+    ///     .map(|a| [a.cos(), a.sin()])
+    ///     .collect();
+    /// let qh = Qh::builder().build_from_iter(points.iter().copied()).unwrap();
+    /// assert_eq!(qh.face_outside_points().count(), 0);
+    ///
+    /// // stop after adding one vertex to the initial triangle
+    /// let qh = Qh::builder().with_flags("TA1").build_from_iter(points.iter().copied()).unwrap();
+    /// assert_eq!(qh.num_vertices(), 4);
+    /// let outside = qh.face_outside_points().collect::<Vec<_>>();
+    /// assert!(!outside.is_empty());
+    /// for (facet, points) in outside {
+    ///     for index in points {
+    ///         assert!(facet.signed_distance_to_point(qh.get_input_point(index).unwrap()).unwrap() > 0.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn face_outside_points(&self) -> impl Iterator<Item = (Facet<'_>, Vec<usize>)> + '_ {
+        let point_index = self.point_index_lookup();
+        self.facets()
+            .map(move |f| (f, f.outside_points().filter_map(point_index).collect::<Vec<_>>()))
+            .filter(|(_, points)| !points.is_empty())
+    }
+
+    /// Checks that a query point has the dimension of the hull
+    fn check_query_point(&self, point: &[f64]) -> Result<(), QhError<'static>> {
+        if point.len() != self.dim {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                format!("the point has {} coordinates, expected {}", point.len(), self.dim),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
@@ -0,0 +1,74 @@
+//! Sending a computed hull to another thread
+
+use std::ops::Deref;
+
+use crate::Qh;
+
+/// A [`Qh`] that can be sent to another thread
+///
+/// [`Qh`] is not [`Send`] since it holds raw pointers into the qhull data structures.
+/// This wrapper is created with [`Qh::into_send`] and only gives shared access
+/// to the hull (through [`Deref`]), it is meant for read-only queries on a hull
+/// that has already been computed.
+/// Use [`QhSend::into_inner`] to get back the [`Qh`].
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let qh = Qh::builder()
+///     .build_from_iter([
+///         [0.0, 0.0],
+///         [1.0, 0.0],
+///         [0.0, 1.0],
+///         [0.25, 0.25],
+///     ])
+///     .unwrap()
+///     .into_send();
+///
+/// let qh = std::thread::spawn(move || {
+///     assert_eq!(qh.num_facets(), 3);
+///     qh
+/// }).join().unwrap();
+///
+/// let qh: Qh = qh.into_inner();
+/// assert_eq!(qh.num_vertices(), 3);
+/// ```
+pub struct QhSend(Qh<'static>);
+
+// # Safety
+// All the memory reachable from a `Qh<'static>` is owned by the instance:
+// * the `qhT` struct and the facets, vertices and sets allocated by qhull,
+//   reentrant qhull keeps no global state
+// * the input coordinates (`'static` means that they are not borrowed from the caller)
+// * the IO buffers and the values in `OwnedValues`
+// * the builder kept for `Qh::reset_with_points`: its configurators are `Send`,
+//   the values they share with `OwnedValues` are behind `Arc`s
+// Moving the whole instance to another thread is therefore sound.
+// `Sync` is not implemented: qhull writes to the `qhT` struct even on queries
+// that look read-only (e.g. visit ids), so the hull must not be shared between threads.
+unsafe impl Send for QhSend {}
+
+impl QhSend {
+    /// Get back the wrapped [`Qh`]
+    pub fn into_inner(self) -> Qh<'static> {
+        self.0
+    }
+}
+
+impl Deref for QhSend {
+    type Target = Qh<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Qh<'static> {
+    /// Wrap the hull in a [`QhSend`] to send it to another thread
+    ///
+    /// The hull should be computed before the conversion,
+    /// [`QhSend`] only gives shared access to it.
+    pub fn into_send(self) -> QhSend {
+        QhSend(self)
+    }
+}
@@ -0,0 +1,205 @@
+//! Owned copies of the faces and vertices of a hull
+//!
+//! [`Facet`] and [`Vertex`] borrow the qhull instance through raw pointers,
+//! so they are neither [`Send`] nor [`Sync`].
+//! The snapshot types copy the data in a single pass, and can then be shared between threads.
+//!
+//! With the `rayon` feature, the snapshots can be processed with parallel iterators.
+
+use std::ops::Deref;
+
+use crate::{Facet, Qh, Vertex};
+
+/// Owned data of a [`Facet`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaceData {
+    /// See [`Facet::id`]
+    pub id: u32,
+    /// Indices of the vertices in the input points, see [`Vertex::index`]
+    pub vertex_indices: Vec<usize>,
+    /// Coordinates of the vertices, in the same order as [`FaceData::vertex_indices`]
+    pub vertices: Vec<Vec<f64>>,
+    /// See [`Facet::normal`]
+    pub normal: Option<Vec<f64>>,
+    /// See [`Facet::offset`]
+    pub offset: Option<f64>,
+    /// See [`Facet::simplicial`]
+    pub simplicial: bool,
+}
+
+impl FaceData {
+    /// Copy the data of a facet
+    pub fn new(qh: &Qh, facet: &Facet) -> Self {
+        let (vertex_indices, vertices) = facet
+            .vertices()
+            .filter_map(|v| Some((v.index(qh)?, v.point()?.to_vec())))
+            .unzip();
+        Self {
+            id: facet.id(),
+            vertex_indices,
+            vertices,
+            normal: facet.normal().map(|n| n.to_vec()),
+            offset: facet.offset(),
+            simplicial: facet.simplicial(),
+        }
+    }
+}
+
+/// Owned data of a [`Vertex`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexData {
+    /// See [`Vertex::id`]
+    pub id: u32,
+    /// See [`Vertex::index`]
+    pub index: Option<usize>,
+    /// See [`Vertex::point`]
+    pub point: Option<Vec<f64>>,
+}
+
+impl VertexData {
+    /// Copy the data of a vertex
+    pub fn new(qh: &Qh, vertex: &Vertex) -> Self {
+        Self {
+            id: vertex.id(),
+            index: vertex.index(qh),
+            point: vertex.point().map(|p| p.to_vec()),
+        }
+    }
+}
+
+macro_rules! snapshot {
+    ($(#[$meta:meta])* $name:ident($data:ident)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct $name(pub Vec<$data>);
+
+        impl Deref for $name {
+            type Target = [$data];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl IntoIterator for $name {
+            type Item = $data;
+            type IntoIter = std::vec::IntoIter<$data>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a $data;
+            type IntoIter = std::slice::Iter<'a, $data>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl rayon::iter::IntoParallelIterator for $name {
+            type Item = $data;
+            type Iter = rayon::vec::IntoIter<$data>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                rayon::iter::IntoParallelIterator::into_par_iter(self.0)
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<'a> rayon::iter::IntoParallelIterator for &'a $name {
+            type Item = &'a $data;
+            type Iter = rayon::slice::Iter<'a, $data>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                rayon::iter::IntoParallelIterator::into_par_iter(&self.0)
+            }
+        }
+    };
+}
+
+snapshot! {
+    /// Owned copy of the [faces](Qh::facets) of a hull, see [`Qh::face_snapshot`]
+    FaceSnapshot(FaceData)
+}
+
+snapshot! {
+    /// Owned copy of the [vertices](Qh::vertices) of a hull, see [`Qh::vertex_snapshot`]
+    VertexSnapshot(VertexData)
+}
+
+impl<'a> Qh<'a> {
+    /// Copy the data of all the facets
+    ///
+    /// The snapshot is [`Send`] and [`Sync`], and can be processed in parallel
+    /// with the `rayon` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// let faces = qh.face_snapshot();
+    /// assert_eq!(faces.len(), 3);
+    ///
+    /// let handle = std::thread::spawn(move || {
+    ///     faces.iter().map(|f| f.vertex_indices.len()).sum::<usize>()
+    /// });
+    /// assert_eq!(handle.join().unwrap(), 6);
+    /// ```
+    pub fn face_snapshot(&self) -> FaceSnapshot {
+        FaceSnapshot(self.facets().map(|f| FaceData::new(self, &f)).collect())
+    }
+
+    /// Copy the data of all the vertices
+    ///
+    /// See [`Qh::face_snapshot`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    ///
+    /// let mut indices = qh
+    ///     .vertex_snapshot()
+    ///     .into_iter()
+    ///     .filter_map(|v| v.index)
+    ///     .collect::<Vec<_>>();
+    /// indices.sort();
+    /// assert_eq!(indices, vec![0, 1, 2]);
+    /// ```
+    #[cfg_attr(feature = "rayon", doc = r#"
+With the `rayon` feature:
+```
+# use qhull::*;
+use rayon::prelude::*;
+let qh = Qh::builder()
+    .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    .unwrap();
+let sum: f64 = qh
+    .vertex_snapshot()
+    .par_iter()
+    .map(|v| v.point.as_ref().unwrap().iter().sum::<f64>())
+    .sum();
+assert_eq!(sum, 2.0);
+```
+"#)]
+    pub fn vertex_snapshot(&self) -> VertexSnapshot {
+        VertexSnapshot(self.vertices().map(|v| VertexData::new(self, &v)).collect())
+    }
+}
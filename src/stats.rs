@@ -0,0 +1,77 @@
+//! Statistics collected by qhull during the computation
+
+use crate::{sys, Qh};
+
+/// Counters and extremes of a computation, see [`Qh::statistics`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QhStats {
+    /// Total number of facets (or cycles of facets) merged
+    pub num_merges: u64,
+    /// Number of merges due to coplanar facets (by distance or by angle)
+    pub num_coplanar_merges: u64,
+    /// Number of vertices deleted because of degenerate facets
+    pub num_degenerate_points: u64,
+    /// Maximum distance of a point above a facet
+    pub max_outside: f64,
+    /// Maximum cosine of the angle between the normals of adjacent facets (the flattest ridge)
+    pub max_cosine: f64,
+}
+
+impl<'a> Qh<'a> {
+    /// Statistics of the computation
+    ///
+    /// The counters are read from qhull's statistics (`qh.qhstat`),
+    /// they can help to tune options like [`QhBuilder::coplanar_tolerance`](crate::QhBuilder::coplanar_tolerance)
+    /// or to diagnose numerical issues.
+    /// qhull only computes its angle statistics when printing them (`Ts`),
+    /// so the maximum cosine is computed from the facet normals.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // the cube facets are merged from triangles
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let stats = qh.statistics();
+    /// assert!(stats.num_merges > 0);
+    /// assert!(stats.max_cosine.abs() < 1e-12);
+    /// assert!(stats.max_outside >= 0.0);
+    /// ```
+    pub fn statistics(&self) -> QhStats {
+        let (num_merges, num_coplanar_merges, num_degenerate_points, max_outside) = unsafe {
+            let qh = &*self.qh.get();
+            let count = |stat: sys::qh_statistics| qh.qhstat.stats[stat as usize].i.max(0) as u64;
+            (
+                count(sys::qh_statistics_Ztotmerge),
+                count(sys::qh_statistics_Zcoplanar) + count(sys::qh_statistics_Zacoplanar),
+                count(sys::qh_statistics_Zdegenvertex),
+                qh.max_outside,
+            )
+        };
+
+        let max_cosine = self
+            .facets()
+            .filter_map(|f| Some((f, f.normal()?)))
+            .flat_map(|(f, a)| {
+                f.neighbors()
+                    .into_iter()
+                    .flat_map(|s| s.iter())
+                    .filter(move |n| n.id() > f.id())
+                    .filter_map(move |n| Some(a.iter().zip(n.normal()?).map(|(x, y)| x * y).sum::<f64>()))
+            })
+            .fold(-1.0, f64::max);
+
+        QhStats {
+            num_merges,
+            num_coplanar_merges,
+            num_degenerate_points,
+            max_outside,
+            max_cosine,
+        }
+    }
+}
@@ -59,6 +59,15 @@ impl TmpFile {
         let _ = unsafe { sys::fread(buffer.as_mut_ptr() as *mut _, 1, size as _, self.file) };
         */
 
+        self.read()
+    }
+
+    /// Read the content written so far without closing the file
+    ///
+    /// The position is moved back to the end of the file, so that further writes are appended.
+    pub fn read(&self) -> Result<Vec<u8>, std::io::Error> {
+        let _ = unsafe { sys::fflush(self.file) };
+
         let mut buffer = Vec::new();
         unsafe {
             sys::rewind(self.file);
@@ -69,6 +78,8 @@ impl TmpFile {
                 }
                 buffer.push(c as u8);
             }
+            // a stream opened for update needs a seek between reading and writing
+            sys::fseek(self.file, 0, sys::SEEK_END as _);
         }
 
         Ok(buffer)
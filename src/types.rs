@@ -6,3 +6,5 @@ mod vertex;
 pub use vertex::*;
 mod ridge;
 pub use ridge::*;
+mod list;
+pub(crate) use list::*;
@@ -1,6 +1,6 @@
-use std::{fmt::Debug, marker::PhantomData, ops::Not};
+use std::{fmt::Debug, iter::FusedIterator, marker::PhantomData, ops::Not};
 
-use crate::{dbg_face_set, helpers::QhTypeRef, sys, Ridge, Set, Vertex};
+use crate::{dbg_face_set, helpers::QhTypeRef, sys, Qh, Ridge, Set, Vertex};
 
 /// A face of the convex hull
 ///
@@ -25,7 +25,7 @@ impl<'a> Debug for Facet<'a> {
             .field("center", &self.center())
             .field("previous", &self.previous().map(|f| f.id()))
             .field("next", &self.next().map(|f| f.id()))
-            .field("vertices", &self.vertices())
+            .field("vertices", &self.vertex_set())
             .field("ridges", &self.ridges())
             .field("neighbors", &dbg_face_set(self.neighbors()))
             .field("outside_set", &self.outside_set())
@@ -87,21 +87,91 @@ impl<'a> Facet<'a> {
         face.maxoutside
     }
 
-    pub fn offset(&self) -> f64 {
+    /// Offset of the hyperplane of the face
+    ///
+    /// The hyperplane is the set of points `x` such that `normal · x + offset = 0`
+    /// (see [`Facet::normal`]), so `-offset` is the signed distance of the hyperplane from the origin.
+    ///
+    /// Returns `None` for the sentinel face.
+    pub fn offset(&self) -> Option<f64> {
+        if self.is_sentinel() {
+            return None;
+        }
         let face = unsafe { self.raw_ref() };
-        face.offset
+        Some(face.offset)
     }
 
+    /// Outward unit normal of the face
+    ///
+    /// Returns `None` for the sentinel face or if the normal has not been computed.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ]).unwrap();
+    /// for facet in qh.facets() {
+    ///     let normal = facet.normal().unwrap();
+    ///     let offset = facet.offset().unwrap();
+    ///     let length: f64 = normal.iter().map(|x| x * x).sum();
+    ///     assert!((length - 1.0).abs() < 1e-12);
+    ///     assert!((offset + 1.0).abs() < 1e-12 || offset.abs() < 1e-12);
+    /// }
+    /// assert!(qh.all_facets().find(|f| f.is_sentinel()).unwrap().normal().is_none());
+    /// ```
     pub fn normal(&self) -> Option<&'a [f64]> {
+        if self.is_sentinel() {
+            return None;
+        }
         unsafe {
             let face = self.raw_ref();
             face.normal
                 .is_null()
                 .not()
-                .then(|| std::slice::from_raw_parts(dbg!(face.normal), self.dim()))
+                .then(|| std::slice::from_raw_parts(face.normal, self.dim()))
         }
     }
 
+    /// Signed distance from the hyperplane of the face to a point
+    ///
+    /// This is `normal · point + offset`:
+    /// positive values mean that the point is above the face (outside the hull),
+    /// negative values that it is below (on the inner side of the face).
+    ///
+    /// Returns `None` for the sentinel face or if the normal has not been computed.
+    ///
+    /// # Panics
+    /// * If `point.len()` is not the dimension of the face
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ]).unwrap();
+    /// // the center of the square is at distance 0.5 from every edge
+    /// for facet in qh.facets() {
+    ///     assert!((facet.signed_distance_to_point(&[0.5, 0.5]).unwrap() + 0.5).abs() < 1e-12);
+    /// }
+    /// // a point outside the hull is above at least one face
+    /// assert!(qh.facets().any(|f| f.signed_distance_to_point(&[3.0, 3.0]).unwrap() > 0.0));
+    /// ```
+    pub fn signed_distance_to_point(&self, point: &[f64]) -> Option<f64> {
+        assert_eq!(point.len(), self.dim(), "point has the wrong dimension");
+        let normal = self.normal()?;
+        let offset = self.offset()?;
+        Some(normal.iter().zip(point).map(|(n, x)| n * x).sum::<f64>() + offset)
+    }
+
     // TODO that union??
 
     pub fn center(&self) -> Option<&'a [f64]> {
@@ -110,7 +180,7 @@ impl<'a> Facet<'a> {
             face.center
                 .is_null()
                 .not()
-                .then(|| std::slice::from_raw_parts(dbg!(face.center), self.dim()))
+                .then(|| std::slice::from_raw_parts(face.center, self.dim()))
         }
     }
 
@@ -124,18 +194,223 @@ impl<'a> Facet<'a> {
         Self::from_ptr(face.next, self.dim())
     }
 
-    pub fn vertices(&self) -> Option<Set<'a, Vertex<'a>>> {
+    /// Iterate over the vertices of the face
+    ///
+    /// A simplicial face has exactly [`dim`](Facet::dim) vertices,
+    /// non-simplicial faces (for example after merging coplanar faces) may have more.
+    /// The iterator is empty if the face has no vertex set (e.g. the sentinel).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// for facet in qh.facets() {
+    ///     assert_eq!(facet.vertices().count(), 2);
+    /// }
+    /// ```
+    pub fn vertices(&self) -> impl FusedIterator<Item = Vertex<'a>> + 'a {
+        self.vertex_set().into_iter().flat_map(|s| s.iter())
+    }
+
+    /// Indices of the vertices of the face in the input points
+    ///
+    /// The indices are given by [`Vertex::index`], in the order of [`Facet::vertices`].
+    /// Vertices that do not belong to the input points are skipped.
+    /// To get the indices of all the faces, [`Qh::faces_as_index_arrays`] is faster.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// let faces = qh.faces_as_index_arrays();
+    /// for (facet, indices) in qh.facets().zip(faces) {
+    ///     assert_eq!(facet.vertex_indices(&qh), indices);
+    /// }
+    /// ```
+    pub fn vertex_indices(&self, qh: &Qh) -> Vec<usize> {
+        self.vertices().filter_map(|v| v.index(qh)).collect()
+    }
+
+    /// Decompose the face into simplices
+    ///
+    /// Each simplex is given by `dim` positions in [`Facet::vertices`].
+    /// A 3-d face is split into triangles by a fan from the first vertex of its polygon,
+    /// `(0, i, i + 1)` in the order of the polygon, so that each triangle is counter-clockwise
+    /// seen from outside.
+    /// In other dimensions a simplicial face is a single simplex, and a non-simplicial face
+    /// is split in the cones from its first vertex over its [ridges](Facet::ridges)
+    /// (qhull's ridges are simplices) that do not contain that vertex.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: its faces are squares
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// let mut area = 0.0;
+    /// for facet in qh.facets() {
+    ///     let vertices: Vec<_> = facet.vertices().map(|v| v.point().unwrap()).collect();
+    ///     let triangles = facet.simplicial_decomposition();
+    ///     assert_eq!(triangles.len(), 2);
+    ///     for triangle in triangles {
+    ///         let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i]]);
+    ///         let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    ///         let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    ///         let cross = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+    ///         area += cross.iter().map(|x| x * x).sum::<f64>().sqrt() / 2.0;
+    ///     }
+    /// }
+    /// assert!((area - qh.surface_area().unwrap()).abs() < 1e-12);
+    /// ```
+    pub fn simplicial_decomposition(&self) -> Vec<Vec<usize>> {
+        let vertices: Vec<Vertex<'a>> = self.vertices().collect();
+        let position = |vertex: &Vertex| vertices.iter().position(|v| v.id() == vertex.id());
+
+        if let Some(polygon) = self.ordered_vertices_3d() {
+            let order: Vec<usize> = polygon.iter().filter_map(|(v, _)| position(v)).collect();
+            return (1..order.len().saturating_sub(1))
+                .map(|i| vec![order[0], order[i], order[i + 1]])
+                .collect();
+        }
+        if self.simplicial() || vertices.len() <= self.dim {
+            return vec![(0..vertices.len()).collect()];
+        }
+
+        let first = vertices[0].id();
+        self.ridges()
+            .into_iter()
+            .flat_map(|s| s.iter())
+            .filter(|r| r.vertices().all(|v| v.id() != first))
+            .map(|r| std::iter::once(0).chain(r.vertices().filter_map(|v| position(&v))).collect())
+            .collect()
+    }
+
+    /// Vertices of a 3-d face in counter-clockwise order, seen from outside
+    ///
+    /// The vertex set of a face is sorted by id, this sorts the vertices by their angle
+    /// around the center of the face in the plane of the face.
+    /// Returns `None` if the face has no normal or the points are not 3-d.
+    pub(crate) fn ordered_vertices_3d(&self) -> Option<Vec<(Vertex<'a>, &'a [f64])>> {
+        let normal = self.normal()?;
+        let mut vertices = self
+            .vertices()
+            .map(|v| Some((v, v.point()?)))
+            .collect::<Option<Vec<_>>>()?;
+        if normal.len() != 3 || vertices.iter().any(|(_, p)| p.len() != 3) {
+            return None;
+        }
+
+        let n = vertices.len() as f64;
+        let center: [f64; 3] =
+            std::array::from_fn(|i| vertices.iter().map(|(_, p)| p[i]).sum::<f64>() / n);
+        let sub = |p: &[f64]| -> [f64; 3] { std::array::from_fn(|i| p[i] - center[i]) };
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        // basis (u, w) of the plane such that u x w = normal
+        let u = sub(vertices[0].1);
+        let w = [
+            normal[1] * u[2] - normal[2] * u[1],
+            normal[2] * u[0] - normal[0] * u[2],
+            normal[0] * u[1] - normal[1] * u[0],
+        ];
+        let angle = |p: &[f64]| {
+            let d = sub(p);
+            dot(d, w).atan2(dot(d, u))
+        };
+        vertices.sort_by(|(_, a), (_, b)| angle(a).total_cmp(&angle(b)));
+        Some(vertices)
+    }
+
+    /// Number of vertices of the face
+    ///
+    /// This reads the size of the vertex set without iterating over it.
+    /// Returns `0` for the sentinel face.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// // the square base of the pyramid is a single face
+    /// assert!(qh.facets().any(|f| f.vertex_count() == 4));
+    /// for facet in qh.all_facets() {
+    ///     assert_eq!(facet.vertex_count(), facet.vertices().count());
+    /// }
+    /// ```
+    pub fn vertex_count(&self) -> usize {
+        if self.is_sentinel() {
+            return 0;
+        }
+        self.vertex_set().map_or(0, |s| s.len())
+    }
+
+    /// The set of vertices of the face
+    ///
+    /// See [`Facet::vertices`] to iterate over them.
+    pub fn vertex_set(&self) -> Option<Set<'a, Vertex<'a>>> {
         let face = unsafe { self.raw_ref() };
         Set::maybe_new(face.vertices, self.dim())
     }
 
+    /// Get the ridges of the face
+    ///
+    /// Qhull only stores explicit ridges for non-simplicial faces and their neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a cube: its faces are squares with 4 ridges each
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     ]).unwrap();
+    /// for facet in qh.facets() {
+    ///     let ridges = facet.ridges().unwrap();
+    ///     assert_eq!(ridges.iter().count(), 4);
+    ///     for ridge in ridges.iter() {
+    ///         for vertex in ridge.vertices() {
+    ///             assert_eq!(vertex.point().unwrap().len(), 3);
+    ///         }
+    ///     }
+    /// }
+    /// ```
     pub fn ridges(&self) -> Option<Set<'a, Ridge<'a>>> {
-        if self.dim() == 0 {
-            None
-        } else {
-            let face = unsafe { self.raw_ref() };
-            Set::maybe_new(face.ridges, self.dim() - 1)
-        }
+        let face = unsafe { self.raw_ref() };
+        Set::maybe_new(face.ridges, self.dim())
+    }
+
+    /// Ridges of the face for which it is the top face
+    ///
+    /// Every ridge is in the ridge set of its two faces,
+    /// this is used to visit each ridge only once.
+    pub(crate) fn top_ridges(&self) -> impl Iterator<Item = Ridge<'a>> + 'a {
+        let id = self.id();
+        self.ridges()
+            .into_iter()
+            .flat_map(|s| s.iter())
+            .filter(move |r| r.adjacent_faces().0.is_some_and(|top| top.id() == id))
     }
 
     pub fn neighbors(&self) -> Option<Set<'a, Facet<'a>>> {
@@ -153,11 +428,41 @@ impl<'a> Facet<'a> {
         Set::maybe_new(face.coplanarset, self.dim())
     }
 
+    /// Coordinates of the points in the outside set of the facet
+    pub(crate) fn outside_points(&self) -> impl FusedIterator<Item = &'a [f64]> + 'a {
+        let face = unsafe { self.raw_ref() };
+        point_set(face.outsideset, self.dim())
+    }
+
+    /// Coordinates of the points in the coplanar set of the facet
+    pub(crate) fn coplanar_points(&self) -> impl FusedIterator<Item = &'a [f64]> + 'a {
+        let face = unsafe { self.raw_ref() };
+        point_set(face.coplanarset, self.dim())
+    }
+
     pub fn visit_id(&self) -> u32 {
         let face = unsafe { self.raw_ref() };
         face.visitid
     }
 
+    /// Qhull id of the face
+    ///
+    /// Ids are unique among the faces of a [`Qh`](crate::Qh) instance,
+    /// two [`Facet`] values refer to the same face if they have the same id.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// let ids = qh.facets().map(|f| f.id()).collect::<std::collections::HashSet<_>>();
+    /// assert_eq!(ids.len(), qh.num_facets());
+    /// ```
     pub fn id(&self) -> u32 {
         let face = unsafe { self.raw_ref() };
         face.id
@@ -322,4 +627,25 @@ impl<'a> QhTypeRef for Facet<'a> {
 //            None
 //        }
 //    }
-//}
\ No newline at end of file
+//}
+
+/// Iterate over a (possibly null) set of points
+///
+/// The elements of the outside and coplanar sets are points (`pointT*`), not vertices.
+fn point_set<'a>(set: *mut sys::setT, dim: usize) -> impl FusedIterator<Item = &'a [f64]> + 'a {
+    let mut ptr = if set.is_null() {
+        std::ptr::null()
+    } else {
+        unsafe { (*set).e.as_ptr() as *const *const f64 }
+    };
+    std::iter::from_fn(move || unsafe {
+        if ptr.is_null() || (*ptr).is_null() {
+            ptr = std::ptr::null();
+            return None;
+        }
+        let point = std::slice::from_raw_parts(*ptr, dim);
+        ptr = ptr.add(1);
+        Some(point)
+    })
+    .fuse()
+}
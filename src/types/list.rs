@@ -0,0 +1,110 @@
+use std::iter::FusedIterator;
+
+use crate::{Facet, Vertex};
+
+/// An element of a qhull linked list terminated by a sentinel
+pub(crate) trait ListItem: Sized {
+    fn next_item(&self) -> Option<Self>;
+    fn previous_item(&self) -> Option<Self>;
+    fn is_sentinel_item(&self) -> bool;
+}
+
+impl<'a> ListItem for Facet<'a> {
+    fn next_item(&self) -> Option<Self> {
+        self.next()
+    }
+
+    fn previous_item(&self) -> Option<Self> {
+        self.previous()
+    }
+
+    fn is_sentinel_item(&self) -> bool {
+        self.is_sentinel()
+    }
+}
+
+impl<'a> ListItem for Vertex<'a> {
+    fn next_item(&self) -> Option<Self> {
+        self.next()
+    }
+
+    fn previous_item(&self) -> Option<Self> {
+        self.previous()
+    }
+
+    fn is_sentinel_item(&self) -> bool {
+        self.is_sentinel()
+    }
+}
+
+/// Iterator over the elements of a qhull list, sentinel excluded
+///
+/// The number of elements is known in advance
+/// (e.g. [`Qh::num_facets`](crate::Qh::num_facets)), so the iterator is exact size.
+/// The list is walked from both ends, the two cursors meet when the remaining count reaches zero.
+pub(crate) struct ListIterator<T: ListItem> {
+    front: Option<T>,
+    back: Option<T>,
+    remaining: usize,
+}
+
+impl<T: ListItem> ListIterator<T> {
+    /// Iterator from the `head` to the `tail` (sentinel) of a list of `len` elements
+    pub(crate) fn new(head: Option<T>, tail: Option<T>, len: usize) -> Self {
+        Self {
+            front: head,
+            back: tail.and_then(|tail| tail.previous_item()),
+            remaining: len,
+        }
+    }
+
+    /// Take `item` if the iterator is not exhausted, returning it with the following cursor
+    fn step(
+        &mut self,
+        item: Option<T>,
+        advance: impl FnOnce(&T) -> Option<T>,
+    ) -> Option<(T, Option<T>)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        match item.filter(|item| !item.is_sentinel_item()) {
+            Some(item) => {
+                self.remaining -= 1;
+                let next = advance(&item);
+                Some((item, next))
+            }
+            None => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+}
+
+impl<T: ListItem> Iterator for ListIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let front = self.front.take();
+        let (item, next) = self.step(front, T::next_item)?;
+        self.front = next;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: ListItem> DoubleEndedIterator for ListIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let back = self.back.take();
+        let (item, previous) = self.step(back, T::previous_item)?;
+        self.back = previous;
+        Some(item)
+    }
+}
+
+impl<T: ListItem> ExactSizeIterator for ListIterator<T> {}
+
+impl<T: ListItem> FusedIterator for ListIterator<T> {}
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, iter::FusedIterator, marker::PhantomData};
 
 use crate::{helpers::QhTypeRef, sys, Facet, Set, Vertex};
 
@@ -20,7 +20,7 @@ impl<'a> Debug for Ridge<'a> {
             .field("merge_vertex_2", &self.merge_vertex_2())
             .field("simplicial_top", &self.simplicial_top())
             .field("simplicial_bottom", &self.simplicial_bottom())
-            .field("vertices", &self.vertices())
+            .field("vertices", &self.vertex_set())
             .field("top", &self.top().id())
             .field("bottom", &self.bottom().id())
             .finish()
@@ -28,11 +28,50 @@ impl<'a> Debug for Ridge<'a> {
 }
 
 impl<'a> Ridge<'a> {
-    pub fn vertices(&self) -> Option<Set<'a, Vertex<'a>>> {
+    /// Iterate over the vertices of the ridge
+    ///
+    /// Works like [`Facet::vertices`], a ridge has `dim - 1` vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// for facet in qh.facets() {
+    ///     for ridge in facet.ridges().iter().flat_map(|s| s.iter()) {
+    ///         assert_eq!(ridge.vertices().count(), 2);
+    ///         let (top, bottom) = ridge.adjacent_faces();
+    ///         assert!(top.unwrap().id() == facet.id() || bottom.unwrap().id() == facet.id());
+    ///     }
+    /// }
+    /// ```
+    pub fn vertices(&self) -> impl FusedIterator<Item = Vertex<'a>> + 'a {
+        self.vertex_set().into_iter().flat_map(|s| s.iter())
+    }
+
+    /// The set of vertices of the ridge
+    pub fn vertex_set(&self) -> Option<Set<'a, Vertex<'a>>> {
         let ridge = unsafe { self.raw_ref() };
         Set::maybe_new(ridge.vertices, self.dim)
     }
 
+    /// The two faces sharing the ridge, `(top, bottom)`
+    ///
+    /// See [`Ridge::top`] and [`Ridge::bottom`].
+    pub fn adjacent_faces(&self) -> (Option<Facet<'a>>, Option<Facet<'a>>) {
+        let ridge = unsafe { self.raw_ref() };
+        (
+            Facet::from_ptr(ridge.top, self.dim),
+            Facet::from_ptr(ridge.bottom, self.dim),
+        )
+    }
+
     pub fn top(&self) -> Facet<'a> {
         let ridge = unsafe { self.raw_ref() };
         Facet::from_ptr(ridge.top, self.dim).unwrap()
@@ -43,6 +82,9 @@ impl<'a> Ridge<'a> {
         Facet::from_ptr(ridge.bottom, self.dim).unwrap()
     }
 
+    /// Qhull id of the ridge
+    ///
+    /// Ids are unique among the ridges of a [`Qh`](crate::Qh) instance.
     pub fn id(&self) -> u32 {
         let ridge = unsafe { self.raw_ref() };
         ridge.id
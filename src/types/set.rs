@@ -1,5 +1,6 @@
 use std::ffi::c_void;
 use std::fmt::Debug;
+use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::ops::Not;
 
@@ -36,7 +37,7 @@ impl<'a, T: QhTypeRef> Set<'a, T> {
     }
 
     /// Iterate over the elements of the set
-    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+    pub fn iter(&self) -> impl FusedIterator<Item = T> + 'a {
         SetIterator::new(self)
     }
 
@@ -45,6 +46,21 @@ impl<'a, T: QhTypeRef> Set<'a, T> {
         set.maxsize
     }
 
+    /// Number of elements in the set
+    ///
+    /// Same as [`qh_setsize`](sys::qh_setsize) but reads the size stored
+    /// at the end of the set directly, without needing a [`Qh`] instance for error reporting.
+    pub(crate) fn len(&self) -> usize {
+        unsafe {
+            let set = &*self.set;
+            let size_elem = &*set.e.as_ptr().add(set.maxsize as usize);
+            match size_elem.i {
+                0 => set.maxsize as usize,
+                size => size as usize - 1,
+            }
+        }
+    }
+
     pub fn size(&self, qh: &Qh) -> usize {
         unsafe {
             sys::qh_setsize(Qh::raw_ptr(qh) as *mut _, self.set) as usize
@@ -89,4 +105,6 @@ impl<'a, T: QhTypeRef> Iterator for SetIterator<'a, T> {
         }
         element
     }
-}
\ No newline at end of file
+}
+
+impl<'a, T: QhTypeRef> FusedIterator for SetIterator<'a, T> {}
@@ -1,6 +1,6 @@
-use std::{fmt::Debug, marker::PhantomData, ops::Not};
+use std::{fmt::Debug, iter::FusedIterator, marker::PhantomData, ops::Not};
 
-use crate::{helpers::QhTypeRef, sys, Qh, QhError, Set};
+use crate::{helpers::QhTypeRef, sys, Facet, Qh, QhError, Set};
 
 /// A vertex of the convex hull
 ///
@@ -60,7 +60,9 @@ impl<'a> Vertex<'a> {
         let end_ptr = unsafe { first_ptr.add(sys::qh_get_num_points(qh.qh.get()) as usize * qh.dim) };
 
         // perform some additional checks if we own the coordinates
-        if let Some(coords_holder) = qh.coords_holder.as_ref() {
+        // and qhull did not copy them (e.g. to rotate or scale the input)
+        let points_copied = unsafe { (*qh.qh.get()).POINTSmalloc != 0 };
+        if let Some(coords_holder) = qh.coords_holder.as_ref().filter(|_| !points_copied) {
             debug_assert_eq!(first_ptr, coords_holder.as_slice().as_ptr());
             debug_assert_eq!(end_ptr, unsafe { coords_holder.as_slice().as_ptr().add(coords_holder.len()) });
         }
@@ -142,8 +144,41 @@ impl<'a> Vertex<'a> {
         }
     }
 
+    /// Coordinates of the vertex
+    ///
+    /// Same as [`Vertex::point`], but also returns `None` for the sentinel vertex.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ]).unwrap();
+    /// let mut points = qh
+    ///     .vertices()
+    ///     .map(|v| v.coordinates().unwrap().to_vec())
+    ///     .collect::<Vec<_>>();
+    /// points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(points, vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0]]);
+    ///
+    /// let sentinel = qh.all_vertices().find(|v| v.is_sentinel()).unwrap();
+    /// assert!(sentinel.coordinates().is_none());
+    /// ```
+    pub fn coordinates(&self) -> Option<&'a [f64]> {
+        if self.is_sentinel() {
+            return None;
+        }
+        self.point()
+    }
+
     /// Qhull id of the vertex
     ///
+    /// Ids are unique among the vertices of a [`Qh`] instance.
+    ///
     /// # Warning
     /// This is not the index of the vertex in the input points, use [`Vertex::index`] for that.
     pub fn id(&self) -> u32 {
@@ -158,10 +193,40 @@ impl<'a> Vertex<'a> {
     }
 
     /// Get the neighbors of the vertex
-    pub fn neighbors(&self) -> Option<Set<'a, Vertex<'a>>> {
+    ///
+    /// These are the facets that contain the vertex, see [`Vertex::adjacent_faces`].
+    pub fn neighbors(&self) -> Option<Set<'a, Facet<'a>>> {
         let vertex = unsafe { self.raw_ref() };
         Set::maybe_new(vertex.neighbors, self.dim())
     }
+
+    /// Iterate over the faces containing the vertex (the star of the vertex)
+    ///
+    /// The iterator is empty if the vertex has no neighbor set (e.g. the sentinel),
+    /// qhull builds the neighbor sets only when needed, see [`Qh::compute_vertex_neighbors`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    /// qh.compute_vertex_neighbors().unwrap();
+    /// for vertex in qh.vertices() {
+    ///     // each vertex of a tetrahedron belongs to 3 faces
+    ///     assert_eq!(vertex.adjacent_faces().count(), 3);
+    ///     for facet in vertex.adjacent_faces() {
+    ///         assert!(facet.vertices().any(|v| v.id() == vertex.id()));
+    ///     }
+    /// }
+    /// ```
+    pub fn adjacent_faces(&self) -> impl FusedIterator<Item = Facet<'a>> + 'a {
+        self.neighbors().into_iter().flat_map(|s| s.iter())
+    }
 }
 
 impl<'a> QhTypeRef for Vertex<'a> {
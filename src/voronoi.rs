@@ -0,0 +1,147 @@
+//! Voronoi diagram queries
+
+use crate::{sys, Qh, QhError, QhErrorKind, Vertex};
+
+impl<'a> Qh<'a> {
+    /// Vertices of the Voronoi cell of an input point
+    ///
+    /// The cell of a point is the polytope whose vertices are the Voronoi centers
+    /// of the Delaunay facets around the point.
+    /// In 2-d the vertices are in counter-clockwise order,
+    /// in other dimensions they are in the order of the facets around the point.
+    ///
+    /// # Errors
+    /// * If the Voronoi diagram has not been computed, see [`Qh::new_voronoi`]
+    /// * If the point is not a vertex of the Delaunay triangulation (e.g. a duplicate point)
+    /// * If the cell is unbounded (the point is on the convex hull of the input)
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a square with its center
+    /// let qh = Qh::new_voronoi([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [1.0, 1.0],
+    ///     [0.5, 0.5],
+    /// ]).unwrap();
+    ///
+    /// // the cell of the center is a diamond
+    /// let cell = qh.voronoi_cell(4).unwrap();
+    /// assert_eq!(cell.len(), 4);
+    /// for (a, b) in cell.iter().zip(cell.iter().cycle().skip(1)) {
+    ///     let cross = (a[0] - 0.5) * (b[1] - 0.5) - (a[1] - 0.5) * (b[0] - 0.5);
+    ///     assert!(cross > 0.0);
+    /// }
+    ///
+    /// // the corners have unbounded cells
+    /// assert!(qh.voronoi_cell(0).is_err());
+    /// ```
+    pub fn voronoi_cell(&self, point_index: usize) -> Result<Vec<Vec<f64>>, QhError<'static>> {
+        self.check_voronoi()?;
+        let vertex = self
+            .vertices()
+            .find(|v| v.index(self) == Some(point_index))
+            .ok_or_else(|| {
+                QhError::from_message(
+                    QhErrorKind::Input,
+                    format!("point {point_index} is not a vertex of the Delaunay triangulation"),
+                )
+            })?;
+        self.vertex_voronoi_cell(&vertex).ok_or_else(|| {
+            QhError::from_message(
+                QhErrorKind::Input,
+                format!("the Voronoi cell of point {point_index} is unbounded"),
+            )
+        })
+    }
+
+    /// Area of the Voronoi cell of each input point (2-d)
+    ///
+    /// Returns a value for each of the [`Qh::num_input_points`] input points:
+    /// [`f64::INFINITY`] for the unbounded cells of the points on the convex hull of the input,
+    /// and `0.0` for the points that are not vertices of the triangulation (e.g. duplicate points).
+    ///
+    /// # Errors
+    /// * If the Voronoi diagram has not been computed, see [`Qh::new_voronoi`]
+    /// * If the input points are not 2-d
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_voronoi([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [1.0, 1.0],
+    ///     [0.5, 0.5],
+    /// ]).unwrap();
+    ///
+    /// let areas = qh.voronoi_areas().unwrap();
+    /// assert_eq!(areas.len(), 5);
+    /// assert!(areas[..4].iter().all(|a| a.is_infinite()));
+    /// // the diamond of the center has diagonals of length 1
+    /// assert!((areas[4] - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn voronoi_areas(&self) -> Result<Vec<f64>, QhError<'static>> {
+        self.check_voronoi()?;
+        if self.dim != 3 {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "Voronoi areas are only computed for 2-d points",
+            ));
+        }
+
+        let mut areas = vec![0.0; self.num_input_points()];
+        for vertex in self.vertices() {
+            let Some(index) = vertex.index(self) else {
+                continue;
+            };
+            areas[index] = match self.vertex_voronoi_cell(&vertex) {
+                Some(cell) => {
+                    let twice_area: f64 = cell
+                        .iter()
+                        .zip(cell.iter().cycle().skip(1))
+                        .map(|(a, b)| a[0] * b[1] - a[1] * b[0])
+                        .sum();
+                    twice_area.abs() / 2.0
+                }
+                None => f64::INFINITY,
+            };
+        }
+        Ok(areas)
+    }
+
+    /// Checks that the Voronoi centers have been computed
+    fn check_voronoi(&self) -> Result<(), QhError<'static>> {
+        let is_voronoi = unsafe { (*self.qh.get()).CENTERtype == sys::qh_CENTER_qh_ASvoronoi };
+        if !is_voronoi {
+            return Err(QhError::from_message(
+                QhErrorKind::Input,
+                "the Voronoi diagram has not been computed, see Qh::new_voronoi",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Voronoi cell of a vertex, `None` if the cell is unbounded
+    fn vertex_voronoi_cell(&self, vertex: &Vertex) -> Option<Vec<Vec<f64>>> {
+        let mut cell = vertex
+            .adjacent_faces()
+            .map(|f| {
+                (!f.upper_delaunay())
+                    .then(|| self.voronoi_center(&f))
+                    .flatten()
+                    .map(|c| c.to_vec())
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if self.dim == 3 {
+            let site = vertex.point()?;
+            let angle = |c: &[f64]| (c[1] - site[1]).atan2(c[0] - site[0]);
+            cell.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+        }
+        Some(cell)
+    }
+}